@@ -5,6 +5,16 @@ pub enum MovementAction {
     Forwards,
     TurnLeft,
     TurnRight,
+    Backwards,
+}
+
+impl MovementAction {
+    pub const ALL: [MovementAction; 4] = [
+        MovementAction::Forwards,
+        MovementAction::TurnLeft,
+        MovementAction::TurnRight,
+        MovementAction::Backwards,
+    ];
 }
 
 #[derive(Actionlike, Clone, Copy, Hash, Debug)]
@@ -12,7 +22,10 @@ pub enum Action {
     MovementForwards,
     MovementTurnLeft,
     MovementTurnRight,
+    MovementBackwards,
+    BreatheFire,
     SwitchLevel,
+    RestartLevel,
 }
 
 impl Action {
@@ -21,6 +34,7 @@ impl Action {
             Action::MovementForwards => Some(MovementAction::Forwards),
             Action::MovementTurnLeft => Some(MovementAction::TurnLeft),
             Action::MovementTurnRight => Some(MovementAction::TurnRight),
+            Action::MovementBackwards => Some(MovementAction::Backwards),
             _ => None,
         }
     }
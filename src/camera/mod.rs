@@ -0,0 +1,5 @@
+mod components;
+mod plugin;
+mod systems;
+
+pub use self::plugin::CameraPlugin;
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::State;
+
+use super::systems::{fit_camera, spawn_camera};
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_exit_system(State::AssetLoading, spawn_camera)
+            .add_system_to_stage(CoreStage::PostUpdate, fit_camera);
+    }
+}
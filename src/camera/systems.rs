@@ -0,0 +1,68 @@
+use bevy::{core_pipeline::clear_color::ClearColorConfig, prelude::*, window::WindowResized};
+
+use crate::grid::{GridPosition, GridScale, GridShape, GridSize};
+
+use super::components::MainCamera;
+
+const CAMERA_PADDING: f32 = 32.0;
+
+pub fn spawn_camera(mut commands: Commands) {
+    commands
+        .spawn(Camera2dBundle {
+            camera_2d: Camera2d {
+                clear_color: ClearColorConfig::Custom(Color::Rgba {
+                    red: 0.15,
+                    green: 0.55,
+                    blue: 0.35,
+                    alpha: 1.0,
+                }),
+            },
+            ..Default::default()
+        })
+        .insert(MainCamera);
+}
+
+pub fn fit_camera(
+    mut resize_events: EventReader<WindowResized>,
+    windows: Res<Windows>,
+    grid_query: Query<(&GridSize, &GridScale, &GridShape)>,
+    changed_grid_query: Query<Entity, Or<(Changed<GridSize>, Changed<GridScale>)>>,
+    positions_query: Query<&GridPosition>,
+    mut camera_query: Query<&mut OrthographicProjection, With<MainCamera>>,
+) {
+    let resized = resize_events.iter().count() > 0;
+
+    if !resized && changed_grid_query.is_empty() {
+        return;
+    }
+
+    let (size, scale, shape) = match grid_query.get_single() {
+        Ok(result) => result,
+        Err(_) => return,
+    };
+
+    let window = match windows.get_primary() {
+        Some(window) => window,
+        None => return,
+    };
+
+    // Some things sit just outside the grid, like the gem counter above it. The camera stays
+    // centred on the grid, so leave the same room on both sides.
+    let overflow = positions_query
+        .iter()
+        .fold(Vec2::ZERO, |overflow, position| {
+            let beyond = |value: i32, cells: u32| (value - (cells as i32 - 1)).max(-value).max(0);
+
+            overflow.max(Vec2::new(
+                beyond(position.x, size.width) as f32 * scale.width,
+                beyond(position.y, size.height) as f32 * shape.row_height(*scale),
+            ))
+        });
+
+    let level_size = shape.extent(*size, *scale) + (overflow + CAMERA_PADDING) * 2.0;
+    let projection_scale = (level_size.x / window.width()).max(level_size.y / window.height());
+
+    for mut projection in camera_query.iter_mut() {
+        projection.scale = projection_scale;
+    }
+}
@@ -1,41 +1,68 @@
+use std::f32::consts::PI;
+
 use bevy::prelude::Component;
 use serde::Deserialize;
 
-use crate::action::MovementAction;
+use crate::{action::MovementAction, grid::GridShape};
 
+/// A way to face on the grid. Square grids use the four straight directions, while hex grids use
+/// `Left`, `Right` and the four diagonals.
 #[derive(Deserialize, Component, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum Direction {
     Up,
     Down,
     Left,
     Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
 }
 
 impl Direction {
-    pub fn process_action(self, action: MovementAction) -> Self {
+    /// Levels are validated on load, so `self` is always one of the shape's directions
+    pub fn process_action(self, action: MovementAction, shape: GridShape) -> Self {
+        let directions = shape.directions();
+        let index = directions
+            .iter()
+            .position(|direction| *direction == self)
+            .expect("direction should exist on the level's grid shape");
+
+        // The directions go anticlockwise, so turning left moves one step along them
         match action {
-            MovementAction::Forwards => self,
-            MovementAction::TurnLeft => match self {
-                Direction::Up => Direction::Left,
-                Direction::Down => Direction::Right,
-                Direction::Left => Direction::Down,
-                Direction::Right => Direction::Up,
-            },
-            MovementAction::TurnRight => match self {
-                Direction::Up => Direction::Right,
-                Direction::Down => Direction::Left,
-                Direction::Left => Direction::Up,
-                Direction::Right => Direction::Down,
-            },
+            MovementAction::Forwards | MovementAction::Backwards => self,
+            MovementAction::TurnLeft => directions[(index + 1) % directions.len()],
+            MovementAction::TurnRight => {
+                directions[(index + directions.len() - 1) % directions.len()]
+            }
         }
     }
 
+    /// Offset to the neighbouring cell, in axial coordinates for the diagonals of a hex grid
     pub fn delta(self) -> (i32, i32) {
         match self {
             Direction::Up => (0, 1),
             Direction::Down => (0, -1),
             Direction::Left => (-1, 0),
             Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, 1),
+            Direction::UpRight => (0, 1),
+            Direction::DownLeft => (0, -1),
+            Direction::DownRight => (1, -1),
+        }
+    }
+
+    /// Angle of the direction in radians, anticlockwise from `Right`
+    pub fn angle(self) -> f32 {
+        match self {
+            Direction::Up => PI / 2.0,
+            Direction::Down => PI * 1.5,
+            Direction::Left => PI,
+            Direction::Right => 0.0,
+            Direction::UpLeft => PI * 2.0 / 3.0,
+            Direction::UpRight => PI / 3.0,
+            Direction::DownLeft => PI * 4.0 / 3.0,
+            Direction::DownRight => PI * 5.0 / 3.0,
         }
     }
 
@@ -45,6 +72,37 @@ impl Direction {
             Direction::Down => Direction::Up,
             Direction::Left => Direction::Right,
             Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::DownRight => Direction::UpLeft,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_turns_go_round_six_directions() {
+        let mut direction = Direction::Right;
+
+        for expected in [
+            Direction::UpRight,
+            Direction::UpLeft,
+            Direction::Left,
+            Direction::DownLeft,
+            Direction::DownRight,
+            Direction::Right,
+        ] {
+            direction = direction.process_action(MovementAction::TurnLeft, GridShape::Hex);
+            assert_eq!(direction, expected);
+        }
+
+        assert_eq!(
+            Direction::Right.process_action(MovementAction::TurnRight, GridShape::Hex),
+            Direction::DownRight
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[derive(AssetCollection, Resource)]
+pub struct ArrowAssets {
+    #[asset(texture_atlas(tile_size_x = 32., tile_size_y = 32., columns = 2, rows = 1))]
+    #[asset(path = "tiles/arrows.png")]
+    pub atlas: Handle<TextureAtlas>,
+}
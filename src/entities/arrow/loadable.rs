@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+
+use crate::{
+    direction::Direction,
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    movement::{Conveyor, OneWay},
+    util::prelude::*,
+};
+
+use super::assets::ArrowAssets;
+
+const ONE_WAY_INDEX: usize = 0;
+const CONVEYOR_INDEX: usize = 1;
+
+#[derive(Bundle)]
+pub struct OneWayBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    one_way: OneWay,
+
+    #[bundle]
+    sprite_sheet: SpriteSheetBundle,
+}
+
+#[derive(Bundle)]
+pub struct ConveyorBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    conveyor: Conveyor,
+
+    #[bundle]
+    sprite_sheet: SpriteSheetBundle,
+}
+
+// The arrows are drawn pointing right
+fn arrow_sprite(
+    atlas: Handle<TextureAtlas>,
+    index: usize,
+    direction: Direction,
+) -> SpriteSheetBundle {
+    SpriteSheetBundle {
+        sprite: TextureAtlasSprite::new(index),
+        texture_atlas: atlas,
+        transform: Transform::from_rotation(Quat::from_rotation_z(direction.angle())),
+        ..Default::default()
+    }
+}
+
+impl OneWayBundle {
+    pub fn new(position: GridPosition, direction: Direction, atlas: Handle<TextureAtlas>) -> Self {
+        OneWayBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Terrain,
+            one_way: OneWay { direction },
+            sprite_sheet: arrow_sprite(atlas, ONE_WAY_INDEX, direction),
+        }
+    }
+}
+
+impl ConveyorBundle {
+    pub fn new(position: GridPosition, direction: Direction, atlas: Handle<TextureAtlas>) -> Self {
+        ConveyorBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Terrain,
+            conveyor: Conveyor { direction },
+            sprite_sheet: arrow_sprite(atlas, CONVEYOR_INDEX, direction),
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for OneWayBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.resource_scope(|world, assets: Mut<ArrowAssets>| {
+            let atlas = assets.atlas.clone();
+
+            world.spawn_batch(scene.one_way.iter().map(move |one_way| {
+                OneWayBundle::new(
+                    GridPosition::new(one_way.position[0], one_way.position[1]),
+                    one_way.direction,
+                    atlas.clone(),
+                )
+            }));
+        });
+    }
+}
+
+impl Loadable<LevelConfig> for ConveyorBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.resource_scope(|world, assets: Mut<ArrowAssets>| {
+            let atlas = assets.atlas.clone();
+
+            world.spawn_batch(scene.conveyors.iter().map(move |conveyor| {
+                ConveyorBundle::new(
+                    GridPosition::new(conveyor.position[0], conveyor.position[1]),
+                    conveyor.direction,
+                    atlas.clone(),
+                )
+            }));
+        });
+    }
+}
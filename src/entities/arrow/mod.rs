@@ -0,0 +1,5 @@
+mod assets;
+mod loadable;
+mod plugin;
+
+pub use self::plugin::ArrowPlugin;
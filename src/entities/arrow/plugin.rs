@@ -0,0 +1,24 @@
+use bevy::{ecs::schedule::StateData, prelude::*};
+use bevy_asset_loader::prelude::*;
+
+use crate::util::prelude::*;
+
+use super::{
+    assets::ArrowAssets,
+    loadable::{ConveyorBundle, OneWayBundle},
+};
+
+pub struct ArrowPlugin;
+
+impl<State: StateData> AssetProvider<State> for ArrowPlugin {
+    fn provide(&self, state: LoadingState<State>) -> LoadingState<State> {
+        state.with_collection::<ArrowAssets>()
+    }
+}
+
+impl Plugin for ArrowPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<OneWayBundle>()
+            .register_loadable::<ConveyorBundle>();
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[derive(AssetCollection, Resource)]
+pub struct FloorAssets {
+    #[asset(texture_atlas(tile_size_x = 32., tile_size_y = 32., columns = 4, rows = 1))]
+    #[asset(path = "tiles/floor.png")]
+    pub atlas: Handle<TextureAtlas>,
+
+    #[asset(texture_atlas(tile_size_x = 32., tile_size_y = 37., columns = 4, rows = 1))]
+    #[asset(path = "tiles/hex_floor.png")]
+    pub hex_atlas: Handle<TextureAtlas>,
+}
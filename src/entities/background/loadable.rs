@@ -1,49 +1,91 @@
 use bevy::prelude::*;
 
 use crate::{
-    grid::GridPosition,
+    grid::{GridPosition, GridShape, RenderLayer},
     level::{LevelComponent, LevelConfig},
+    movement::Blocker,
     util::prelude::*,
 };
 
+use super::assets::FloorAssets;
+
 #[derive(Bundle)]
 pub struct TileBundle {
     component: LevelComponent,
     position: GridPosition,
+    layer: RenderLayer,
 
     #[bundle]
-    sprite: SpriteBundle,
+    sprite_sheet: SpriteSheetBundle,
 }
 
 impl TileBundle {
-    pub fn new(position: GridPosition) -> Self {
+    pub fn new(position: GridPosition, atlas: Handle<TextureAtlas>) -> Self {
         TileBundle {
             component: LevelComponent,
             position,
-            sprite: SpriteBundle {
-                sprite: Sprite {
-                    color: Color::rgba(0.2, 0.2, 0.2, 0.6),
-                    ..Default::default()
-                },
-                transform: Transform {
-                    scale: Vec3::new(30.0, 30.0, 30.0),
-                    ..Default::default()
-                },
+            layer: RenderLayer::Floor,
+            sprite_sheet: SpriteSheetBundle {
+                sprite: TextureAtlasSprite::new(floor_variant(position)),
+                texture_atlas: atlas,
                 ..Default::default()
             },
         }
     }
 }
 
+// Scatter the decorated floor variants deterministically, so a level looks the same every time
+fn floor_variant(position: GridPosition) -> usize {
+    let hash = position.x.wrapping_mul(73_856_093) ^ position.y.wrapping_mul(19_349_663);
+
+    match hash.rem_euclid(8) {
+        0 => 1,
+        1 => 2,
+        2 => 3,
+        _ => 0,
+    }
+}
+
 impl Loadable<LevelConfig> for TileBundle {
     fn from_scene(world: &mut World, scene: &LevelConfig) {
-        world.spawn_batch((0..scene.size[0]).flat_map(|x| {
-            (0..scene.size[1]).map(move |y| {
-                TileBundle::new(GridPosition {
-                    x: x as i32,
-                    y: y as i32,
-                })
-            })
-        }));
+        world.resource_scope(|world, assets: Mut<FloorAssets>| {
+            let atlas = match scene.shape {
+                GridShape::Square => assets.atlas.clone(),
+                GridShape::Hex => assets.hex_atlas.clone(),
+            };
+            let void = scene.void_cells();
+            let height = scene.size[1] as i32;
+
+            world.spawn_batch(
+                (0..scene.size[0] as i32)
+                    .flat_map(move |x| (0..height).map(move |y| GridPosition::new(x, y)))
+                    .filter(move |position| !void.contains(position))
+                    .map(move |position| TileBundle::new(position, atlas.clone())),
+            );
+        });
+    }
+}
+
+/// An invisible cell outside the level's shape
+#[derive(Bundle)]
+pub struct VoidBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    blocker: Blocker,
+}
+
+impl VoidBundle {
+    pub fn new(position: GridPosition) -> Self {
+        VoidBundle {
+            component: LevelComponent,
+            position,
+            blocker: Blocker,
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for VoidBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.void_cells().into_iter().map(VoidBundle::new));
     }
 }
@@ -1,13 +1,24 @@
-use bevy::prelude::*;
+use bevy::{ecs::schedule::StateData, prelude::*};
+use bevy_asset_loader::prelude::*;
 
 use crate::util::prelude::*;
 
-use super::loadable::TileBundle;
+use super::{
+    assets::FloorAssets,
+    loadable::{TileBundle, VoidBundle},
+};
 
 pub struct BackgroundPlugin;
 
+impl<State: StateData> AssetProvider<State> for BackgroundPlugin {
+    fn provide(&self, state: LoadingState<State>) -> LoadingState<State> {
+        state.with_collection::<FloorAssets>()
+    }
+}
+
 impl Plugin for BackgroundPlugin {
     fn build(&self, app: &mut App) {
-        app.register_loadable::<TileBundle>();
+        app.register_loadable::<TileBundle>()
+            .register_loadable::<VoidBundle>();
     }
 }
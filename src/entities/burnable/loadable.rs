@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    movement::{Blocker, Flammable},
+    util::prelude::*,
+};
+
+/// A wooden block, in the way until a dragon burns it down
+#[derive(Bundle)]
+pub struct BurnableBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    blocker: Blocker,
+    flammable: Flammable,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl BurnableBundle {
+    pub fn new(position: GridPosition) -> Self {
+        BurnableBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Wall,
+            blocker: Blocker,
+            flammable: Flammable::Consumed,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.55, 0.36, 0.2),
+                    custom_size: Some(Vec2::splat(28.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for BurnableBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        let burnable = scene
+            .burnable
+            .iter()
+            .flat_map(|burnable_range| burnable_range.positions())
+            .collect::<HashSet<_>>();
+
+        world.spawn_batch(burnable.into_iter().map(BurnableBundle::new));
+    }
+}
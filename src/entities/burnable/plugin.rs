@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use crate::util::prelude::*;
+
+use super::loadable::BurnableBundle;
+
+pub struct BurnablePlugin;
+
+impl Plugin for BurnablePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<BurnableBundle>();
+    }
+}
@@ -1,4 +1,29 @@
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Entity, Timer};
+
+use crate::direction::Direction;
 
 #[derive(Component)]
 pub struct DragonHead;
+
+/// Which dragons this one can win by meeting
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DragonPair(pub u32);
+
+/// Body segments of a dragon, ordered from the tail to the segment behind the head
+#[derive(Component, Default)]
+pub struct DragonBody(pub Vec<Entity>);
+
+/// Most body segments a dragon can have before its tail starts retracting
+#[derive(Component, Clone, Copy, Debug)]
+pub struct MaxLength(pub u32);
+
+/// The direction the head entered this segment's cell in (none for the tail), and left it in
+#[derive(Component, Clone, Copy, Debug)]
+pub struct BodySegment {
+    pub from: Option<Direction>,
+    pub to: Direction,
+}
+
+/// A puff of fire breathed by a dragon, fading out as the timer runs
+#[derive(Component)]
+pub struct Flame(pub Timer);
@@ -2,54 +2,90 @@ use bevy::prelude::*;
 
 use crate::{
     direction::Direction,
-    grid::GridPosition,
-    level::{LevelComponent, LevelConfig},
-    movement::Movement,
+    grid::{GridPosition, RenderLayer},
+    level::{DragonConfig, LevelComponent, LevelConfig},
+    movement::{Inventory, Movement},
     util::prelude::*,
 };
 
-use super::{assets::DragonAssets, components::DragonHead};
+use super::{
+    assets::DragonAssets,
+    components::{DragonBody, DragonHead, DragonPair, MaxLength},
+};
+
+/// Tint for a pair of dragons, leaving the default pair untinted
+pub fn pair_colour(pair: DragonPair) -> Color {
+    match pair.0 {
+        0 => Color::WHITE,
+        pair => Color::hsl((pair * 137 % 360) as f32, 0.8, 0.7),
+    }
+}
 
 #[derive(Bundle)]
 pub struct DragonBundle {
     head: DragonHead,
+    pair: DragonPair,
+    body: DragonBody,
     component: LevelComponent,
     direction: Direction,
     position: GridPosition,
+    layer: RenderLayer,
     movement: Movement,
+    inventory: Inventory,
 
     #[bundle]
     sprite_sheet: SpriteSheetBundle,
 }
 
 impl DragonBundle {
-    fn new(direction: Direction, position: GridPosition, atlas: Handle<TextureAtlas>) -> Self {
+    fn new(
+        direction: Direction,
+        position: GridPosition,
+        pair: DragonPair,
+        atlas: Handle<TextureAtlas>,
+    ) -> Self {
         Self {
             head: DragonHead,
+            pair,
+            body: DragonBody::default(),
             component: LevelComponent,
             direction,
             position,
+            layer: RenderLayer::Head,
             movement: Movement::default(),
+            inventory: Inventory::default(),
             sprite_sheet: SpriteSheetBundle {
+                sprite: TextureAtlasSprite {
+                    color: pair_colour(pair),
+                    ..Default::default()
+                },
                 texture_atlas: atlas,
                 ..Default::default()
             },
         }
     }
+
+    /// Spawn a dragon, either while loading a level or hatching one from an egg mid-level
+    pub fn spawn(world: &mut World, dragon: &DragonConfig) {
+        let atlas = world.resource::<DragonAssets>().atlas.clone();
+
+        let mut entity = world.spawn(DragonBundle::new(
+            dragon.direction,
+            GridPosition::new(dragon.position[0], dragon.position[1]),
+            DragonPair(dragon.pair),
+            atlas,
+        ));
+
+        if let Some(max_length) = dragon.max_length {
+            entity.insert(MaxLength(max_length));
+        }
+    }
 }
 
 impl Loadable<LevelConfig> for DragonBundle {
     fn from_scene(world: &mut World, level: &LevelConfig) {
-        world.resource_scope(|world, assets: Mut<DragonAssets>| {
-            let atlas = assets.atlas.clone();
-
-            world.spawn_batch(level.dragons.iter().map(move |dragon| {
-                DragonBundle::new(
-                    dragon.direction,
-                    GridPosition::new(dragon.position[0], dragon.position[1]),
-                    atlas.clone(),
-                )
-            }));
-        });
+        for dragon in &level.dragons {
+            DragonBundle::spawn(world, dragon);
+        }
     }
 }
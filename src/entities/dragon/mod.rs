@@ -4,4 +4,8 @@ mod loadable;
 mod plugin;
 mod systems;
 
-pub use self::plugin::DragonPlugin;
+pub use self::{
+    components::{BodySegment, DragonHead, MaxLength},
+    loadable::DragonBundle,
+    plugin::DragonPlugin,
+};
@@ -2,12 +2,20 @@ use bevy::{ecs::schedule::StateData, prelude::*};
 use bevy_asset_loader::prelude::*;
 use iyes_loopless::prelude::*;
 
-use crate::{level::WinTimer, stage::EntityProcessingStage, util::prelude::*, State};
+use crate::{
+    level::{FallTimer, LevelFailed, WinTimer},
+    stage::{EntityFinalisationStage, EntityProcessingStage},
+    util::prelude::*,
+    State,
+};
 
 use super::{
     assets::DragonAssets,
     loadable::DragonBundle,
-    systems::{check_win, rotate_dragons, spawn_body},
+    systems::{
+        burn_out_flames, celebrate_win, check_win, orient_body, retract_tail, rotate_dragons,
+        spawn_body, spawn_flames,
+    },
 };
 
 pub struct DragonPlugin;
@@ -26,11 +34,26 @@ impl Plugin for DragonPlugin {
                 SystemSet::new()
                     .with_system(rotate_dragons)
                     .with_system(spawn_body.run_in_state(State::InLevel))
+                    .with_system(spawn_flames.run_in_state(State::InLevel))
+                    .with_system(burn_out_flames)
                     .with_system(
                         check_win
                             .run_unless_resource_exists::<WinTimer>()
+                            .run_unless_resource_exists::<LevelFailed>()
+                            .run_unless_resource_exists::<FallTimer>()
+                            .run_in_state(State::InLevel),
+                    )
+                    .with_system(
+                        celebrate_win
+                            .run_if_resource_exists::<WinTimer>()
                             .run_in_state(State::InLevel),
                     ),
+            )
+            .add_system_set_to_stage(
+                EntityFinalisationStage,
+                SystemSet::new()
+                    .with_system(retract_tail)
+                    .with_system(orient_body.after(retract_tail)),
             );
     }
 }
@@ -1,71 +1,265 @@
-use std::f32::consts::PI;
+use std::{collections::HashSet, f32::consts::PI};
 
 use bevy::{ecs::query::WorldQuery, prelude::*};
 
 use crate::{
     direction::Direction,
-    grid::GridPosition,
+    entities::gem::GemCount,
+    grid::{GridPosition, GridShape, GridSize, GridWrap, RenderLayer},
     level::{LevelComponent, WinTimer},
-    movement::Movement,
+    movement::{FireBreathed, Movement, Portal, Topology},
 };
 
-use super::{assets::DragonAssets, components::DragonHead};
+use super::{
+    assets::DragonAssets,
+    components::{BodySegment, DragonBody, DragonHead, DragonPair, Flame, MaxLength},
+    loadable::pair_colour,
+};
+
+const STRAIGHT_INDEX: usize = 1;
+const CORNER_INDEX: usize = 2;
+const TAIL_INDEX: usize = 3;
 
 #[derive(WorldQuery)]
+#[world_query(mutable)]
 pub struct SpawnBodyDragonQuery<'w> {
     position: &'w GridPosition,
     direction: &'w Direction,
+    pair: &'w DragonPair,
     movement: &'w Movement,
+    body: &'w mut DragonBody,
+}
+
+// The head and body art points left
+fn direction_angle(direction: Direction) -> f32 {
+    direction.angle() - PI
+}
+
+// The corner sprite joins the right and bottom edges of its cell, so the angle depends on which
+// pair of edges the segment joins rather than on a single direction
+fn corner_angle(a: Direction, b: Direction) -> f32 {
+    (PI / 180.0)
+        * match (a, b) {
+            (Direction::Right, Direction::Down) | (Direction::Down, Direction::Right) => 0.0,
+            (Direction::Up, Direction::Right) | (Direction::Right, Direction::Up) => 90.0,
+            (Direction::Left, Direction::Up) | (Direction::Up, Direction::Left) => 180.0,
+            _ => 270.0,
+        }
+}
+
+fn segment_sprite(segment: &BodySegment) -> (usize, f32) {
+    let from = match segment.from {
+        Some(from) => from,
+        None => return (TAIL_INDEX, direction_angle(segment.to)),
+    };
+
+    // How far the body turns on this cell, between -PI and PI
+    let turn = (segment.to.angle() - from.angle() + PI).rem_euclid(PI * 2.0) - PI;
+
+    if turn.abs() < 0.01 || (turn.abs() - PI).abs() < 0.01 {
+        (STRAIGHT_INDEX, direction_angle(segment.to))
+    } else if (turn.abs() - PI / 2.0).abs() < 0.01 {
+        (CORNER_INDEX, corner_angle(from.opposite(), segment.to))
+    } else {
+        // Hex turns are gentle enough to draw as a straight piece angled halfway between
+        (STRAIGHT_INDEX, direction_angle(from) + turn / 2.0)
+    }
 }
 
 pub fn spawn_body(
     mut commands: Commands,
     assets: Res<DragonAssets>,
-    dragons: Query<SpawnBodyDragonQuery, (With<DragonHead>, Changed<Movement>)>,
+    grid_query: Query<&GridShape>,
+    mut dragons: Query<SpawnBodyDragonQuery, (With<DragonHead>, Changed<Movement>)>,
+    segments: Query<(&BodySegment, &GridPosition)>,
 ) {
-    for dragon in dragons.iter() {
-        if dragon.movement.0.is_none() {
+    let shape = grid_query.get_single().copied().unwrap_or_default();
+
+    for mut dragon in dragons.iter_mut() {
+        if dragon.movement.path.is_empty() {
             continue;
         }
 
+        // Backing up along the trail winds the body back in rather than leaving more of it
+        let path = &dragon.movement.path;
+        let retracting = dragon.movement.backwards
+            && path.len() <= dragon.body.0.len()
+            && path
+                .iter()
+                .zip(dragon.body.0.iter().rev())
+                .all(|(position, entity)| {
+                    segments
+                        .get(*entity)
+                        .is_ok_and(|(_, segment_position)| segment_position == position)
+                });
+
+        if retracting {
+            let retained = dragon.body.0.len() - path.len();
+
+            for entity in dragon.body.0.drain(retained..) {
+                commands.entity(entity).despawn();
+            }
+
+            continue;
+        }
+
+        let mut from = dragon
+            .body
+            .0
+            .last()
+            .and_then(|entity| segments.get(*entity).ok())
+            .map(|(segment, _)| segment.to);
+
+        // Leave a segment on the starting cell and on every cell crossed on the way
+        let crossed = std::iter::once(*dragon.position)
+            .chain(path[..path.len() - 1].iter().copied())
+            .zip(path.iter().copied())
+            .collect::<Vec<_>>();
+
+        for (position, next_position) in crossed {
+            // Portals and wrapping edges break the chain of neighbouring cells, so fall back to
+            // the way the head stepped
+            let to = position
+                .direction_to(next_position, shape)
+                .or(dragon.movement.direction)
+                .unwrap_or(*dragon.direction);
+
+            let segment = commands
+                .spawn(SpriteSheetBundle {
+                    sprite: TextureAtlasSprite {
+                        color: pair_colour(*dragon.pair),
+                        ..Default::default()
+                    },
+                    texture_atlas: assets.atlas.clone(),
+                    ..Default::default()
+                })
+                .insert(LevelComponent)
+                .insert(position)
+                .insert(RenderLayer::Body)
+                .insert(BodySegment { from, to })
+                .id();
+
+            dragon.body.0.push(segment);
+            from = Some(to);
+        }
+    }
+}
+
+/// Remove the oldest segments of dragons that have grown past their maximum length
+pub fn retract_tail(
+    mut commands: Commands,
+    mut dragons: Query<(&MaxLength, &mut DragonBody), Changed<DragonBody>>,
+    mut segments: Query<&mut BodySegment>,
+) {
+    for (max_length, mut body) in dragons.iter_mut() {
+        let excess = body.0.len().saturating_sub(max_length.0 as usize);
+
+        if excess == 0 {
+            continue;
+        }
+
+        for entity in body.0.drain(..excess) {
+            commands.entity(entity).despawn();
+        }
+
+        if let Some(tail) = body.0.first() {
+            if let Ok(mut segment) = segments.get_mut(*tail) {
+                segment.from = None;
+            }
+        }
+    }
+}
+
+pub fn orient_body(
+    mut q: Query<(&BodySegment, &mut TextureAtlasSprite, &mut Transform), Changed<BodySegment>>,
+) {
+    for (segment, mut sprite, mut transform) in q.iter_mut() {
+        let (index, angle) = segment_sprite(segment);
+
+        sprite.index = index;
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+}
+
+pub fn spawn_flames(mut commands: Commands, mut fire_events: EventReader<FireBreathed>) {
+    for event in fire_events.iter() {
         commands
-            .spawn(SpriteSheetBundle {
-                sprite: TextureAtlasSprite {
-                    index: 1,
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(1.0, 0.5, 0.1),
+                    custom_size: Some(Vec2::splat(24.0)),
                     ..Default::default()
                 },
-                texture_atlas: assets.atlas.clone(),
                 ..Default::default()
             })
             .insert(LevelComponent)
-            .insert(*dragon.position)
-            .insert(*dragon.direction);
+            .insert(event.position)
+            .insert(RenderLayer::Head)
+            .insert(Flame(Timer::from_seconds(0.3, TimerMode::Once)));
+    }
+}
+
+pub fn burn_out_flames(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut flames: Query<(Entity, &mut Flame, &mut Sprite)>,
+) {
+    for (entity, mut flame, mut sprite) in flames.iter_mut() {
+        flame.0.tick(time.delta());
+        sprite.color.set_a(flame.0.percent_left());
+
+        if flame.0.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub fn celebrate_win(timer: Res<WinTimer>, mut heads: Query<&mut Transform, With<DragonHead>>) {
+    let bounce = (timer.0.percent() * PI * 3.0).sin().abs() * 0.3;
+
+    for mut transform in heads.iter_mut() {
+        transform.scale = Vec3::splat(1.0 + bounce);
     }
 }
 
 pub fn rotate_dragons(mut q: Query<(&Direction, &mut Transform), Changed<Direction>>) {
     for (direction, mut transform) in q.iter_mut() {
-        transform.rotation = Quat::from_rotation_z(
-            (PI / 180.0)
-                * match direction {
-                    Direction::Up => 270.0,
-                    Direction::Down => 90.0,
-                    Direction::Left => 0.0,
-                    Direction::Right => 180.0,
-                },
-        );
+        transform.rotation = Quat::from_rotation_z(direction_angle(*direction));
     }
 }
 
 pub fn check_win(
     mut commands: Commands,
-    dragons: Query<(&GridPosition, &Direction), With<DragonHead>>,
+    dragons: Query<(&GridPosition, &Direction, &DragonPair), With<DragonHead>>,
+    grid_query: Query<(&GridSize, &GridWrap)>,
+    portals_query: Query<(&GridPosition, &Portal)>,
+    gems: Res<GemCount>,
 ) {
-    let dragons_opposite = dragons
+    if !gems.satisfied() {
+        return;
+    }
+
+    let grid = grid_query
+        .get_single()
+        .ok()
+        .map(|(size, wrap)| (*size, *wrap));
+    let topology = Topology::new(grid, portals_query.iter());
+
+    let pairs = dragons
+        .iter()
+        .map(|(_, _, pair)| *pair)
+        .collect::<HashSet<_>>();
+
+    let met_pairs = dragons
         .iter_combinations::<2>()
-        .any(|[a, b]| a.0.apply_direction(*a.1) == *b.0 && a.1.opposite() == *b.1);
+        .filter(|[a, b]| {
+            a.2 == b.2 && topology.facing(*a.0, *a.1) == *b.0 && a.1.opposite() == *b.1
+        })
+        .map(|[a, _]| *a.2)
+        .collect::<HashSet<_>>();
 
-    if dragons_opposite {
-        commands.insert_resource(WinTimer(Timer::from_seconds(0.5, TimerMode::Once)));
+    // Every pair has to meet, so levels with more dragons need them all to find their partners
+    if !pairs.is_empty() && met_pairs == pairs {
+        commands.insert_resource(WinTimer(Timer::from_seconds(0.9, TimerMode::Once)));
     }
 }
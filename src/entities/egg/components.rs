@@ -0,0 +1,7 @@
+use bevy::prelude::Component;
+
+use crate::level::DragonConfig;
+
+/// Hatches into the dragon it holds when a head faces it
+#[derive(Component)]
+pub struct Egg(pub DragonConfig);
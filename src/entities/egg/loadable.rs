@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{DragonConfig, LevelComponent, LevelConfig},
+    movement::Blocker,
+    util::prelude::*,
+};
+
+use super::components::Egg;
+
+#[derive(Bundle)]
+pub struct EggBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    egg: Egg,
+    blocker: Blocker,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl EggBundle {
+    pub fn new(dragon: DragonConfig) -> Self {
+        EggBundle {
+            component: LevelComponent,
+            position: GridPosition::new(dragon.position[0], dragon.position[1]),
+            layer: RenderLayer::Object,
+            egg: Egg(dragon),
+            blocker: Blocker,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.95, 0.92, 0.8),
+                    custom_size: Some(Vec2::new(16.0, 20.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for EggBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.eggs.iter().copied().map(EggBundle::new));
+    }
+}
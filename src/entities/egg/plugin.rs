@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::{
+    level::{LevelFailed, WinTimer},
+    util::prelude::*,
+    State,
+};
+
+use super::{loadable::EggBundle, systems::hatch_eggs};
+
+pub struct EggPlugin;
+
+impl Plugin for EggPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<EggBundle>().add_system_to_stage(
+            CoreStage::PostUpdate,
+            hatch_eggs
+                .run_unless_resource_exists::<LevelFailed>()
+                .run_unless_resource_exists::<WinTimer>()
+                .run_in_state(State::InLevel),
+        );
+    }
+}
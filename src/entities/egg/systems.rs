@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    direction::Direction,
+    entities::dragon::{DragonBundle, DragonHead},
+    grid::{GridPosition, GridSize, GridWrap},
+    movement::{Portal, Topology},
+};
+
+use super::components::Egg;
+
+pub fn hatch_eggs(
+    mut commands: Commands,
+    moved_query: Query<
+        (),
+        (
+            With<DragonHead>,
+            Or<(Changed<GridPosition>, Changed<Direction>)>,
+        ),
+    >,
+    heads_query: Query<(&GridPosition, &Direction), With<DragonHead>>,
+    grid_query: Query<(&GridSize, &GridWrap)>,
+    portals_query: Query<(&GridPosition, &Portal)>,
+    eggs_query: Query<(Entity, &GridPosition, &Egg)>,
+) {
+    // Heads can come to face an egg by moving, turning, falling or being carried along
+    if moved_query.is_empty() {
+        return;
+    }
+
+    let grid = grid_query
+        .get_single()
+        .ok()
+        .map(|(size, wrap)| (*size, *wrap));
+    let topology = Topology::new(grid, portals_query.iter());
+
+    let faced = heads_query
+        .iter()
+        .map(|(position, direction)| topology.facing(*position, *direction))
+        .collect::<HashSet<_>>();
+
+    for (entity, position, egg) in eggs_query.iter() {
+        if faced.contains(position) {
+            let dragon = egg.0;
+
+            commands.entity(entity).despawn();
+            commands.add(move |world: &mut World| DragonBundle::spawn(world, &dragon));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::Component;
+
+use crate::grid::GridPosition;
+
+/// A knight walking its patrol one step for every move the dragons make, waiting whenever the
+/// next cell is taken
+#[derive(Component)]
+pub struct Enemy {
+    /// Cells visited in order, looping back to the first
+    pub path: Vec<GridPosition>,
+    pub step: usize,
+}
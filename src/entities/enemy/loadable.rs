@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    movement::Blocker,
+    util::prelude::*,
+};
+
+use super::components::Enemy;
+
+#[derive(Bundle)]
+pub struct EnemyBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    enemy: Enemy,
+    blocker: Blocker,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl EnemyBundle {
+    pub fn new(path: Vec<GridPosition>) -> Self {
+        EnemyBundle {
+            component: LevelComponent,
+            position: path[0],
+            layer: RenderLayer::Head,
+            enemy: Enemy { path, step: 0 },
+            blocker: Blocker,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.75, 0.78, 0.85),
+                    custom_size: Some(Vec2::splat(22.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for EnemyBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(
+            scene
+                .enemies
+                .iter()
+                .filter(|enemy| !enemy.path.is_empty())
+                .map(|enemy| {
+                    EnemyBundle::new(
+                        enemy
+                            .path
+                            .iter()
+                            .map(|position| GridPosition::new(position[0], position[1]))
+                            .collect(),
+                    )
+                }),
+        );
+    }
+}
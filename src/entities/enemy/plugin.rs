@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::{
+    level::{LevelFailed, WinTimer},
+    util::prelude::*,
+    State,
+};
+
+use super::{loadable::EnemyBundle, systems::patrol_enemies};
+
+pub struct EnemyPlugin;
+
+impl Plugin for EnemyPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<EnemyBundle>().add_system_to_stage(
+            CoreStage::PostUpdate,
+            patrol_enemies
+                .run_unless_resource_exists::<LevelFailed>()
+                .run_unless_resource_exists::<WinTimer>()
+                .run_in_state(State::InLevel),
+        );
+    }
+}
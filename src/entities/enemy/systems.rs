@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    direction::Direction,
+    entities::dragon::{BodySegment, DragonHead},
+    grid::GridPosition,
+    level::{FailReason, LevelFailed},
+    movement::{BoardQuery, Moved},
+};
+
+use super::components::Enemy;
+
+pub fn patrol_enemies(
+    mut commands: Commands,
+    mut moved_events: EventReader<Moved>,
+    board_query: BoardQuery,
+    heads_query: Query<(&GridPosition, &Direction), With<DragonHead>>,
+    segments_query: Query<&GridPosition, With<BodySegment>>,
+    mut enemies_query: Query<(Entity, &GridPosition, &mut Enemy)>,
+) {
+    if moved_events.iter().count() == 0 {
+        return;
+    }
+
+    let board = board_query.board();
+
+    let heads = heads_query
+        .iter()
+        .map(|(position, _)| *position)
+        .collect::<HashSet<_>>();
+
+    // Cells a head is looking straight at, which enemies flee from
+    let faced = heads_query
+        .iter()
+        .map(|(position, direction)| board.facing(*position, *direction))
+        .collect::<HashSet<_>>();
+
+    // Knights that aren't scared off this turn, with the step they would take next
+    let mut knights = Vec::new();
+
+    for (entity, position, enemy) in enemies_query.iter() {
+        if faced.contains(position) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let step = (enemy.step + 1) % enemy.path.len();
+        knights.push((entity, *position, step, enemy.path[step]));
+    }
+
+    let knight_cells = knights
+        .iter()
+        .map(|&(_, position, _, _)| position)
+        .collect::<HashSet<_>>();
+
+    let segments = segments_query.iter().copied().collect::<HashSet<_>>();
+
+    // Knights can't climb over a dragon's body. Other knights only block the cells they stay in
+    // or move into this turn, which is worked out below.
+    let mut waiting = knights
+        .iter()
+        .map(|&(_, position, _, next)| {
+            next != position
+                && (segments.contains(&next)
+                    || !(board.is_open(next) || knight_cells.contains(&next)))
+        })
+        .collect::<Vec<_>>();
+
+    loop {
+        let staying = knights
+            .iter()
+            .zip(&waiting)
+            .filter(|&(&(_, position, _, next), &waiting)| waiting || next == position)
+            .map(|(&(_, position, _, _), _)| position)
+            .collect::<HashSet<_>>();
+
+        let moving = |index: usize| !waiting[index] && knights[index].3 != knights[index].1;
+
+        // A knight walking into one that stays put has to wait as well
+        if let Some(index) =
+            (0..knights.len()).find(|&index| moving(index) && staying.contains(&knights[index].3))
+        {
+            waiting[index] = true;
+            continue;
+        }
+
+        // Only one knight fits in each cell, and two can't swap places by walking through
+        // each other
+        let mut entering = HashSet::new();
+        let blocked = (0..knights.len()).find(|&index| {
+            let (_, position, _, next) = knights[index];
+
+            moving(index)
+                && (!entering.insert(next)
+                    || (0..knights.len()).any(|other| {
+                        moving(other) && knights[other].1 == next && knights[other].3 == position
+                    }))
+        });
+
+        match blocked {
+            Some(index) => waiting[index] = true,
+            None => break,
+        }
+    }
+
+    let mut caught = false;
+
+    for (&(entity, _, step, next), waiting) in knights.iter().zip(waiting) {
+        if waiting {
+            continue;
+        }
+
+        if let Ok((_, _, mut enemy)) = enemies_query.get_mut(entity) {
+            enemy.step = step;
+        }
+
+        commands.entity(entity).insert(next);
+
+        if heads.contains(&next) {
+            caught = true;
+        } else if faced.contains(&next) {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    if caught {
+        commands.insert_resource(LevelFailed(FailReason::DragonCaught));
+    }
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::Component;
+
+/// Eaten by the first head to reach it, letting that dragon grow `growth` segments longer
+#[derive(Component)]
+pub struct Food {
+    pub growth: u32,
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    util::prelude::*,
+};
+
+use super::components::Food;
+
+#[derive(Bundle)]
+pub struct FoodBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    food: Food,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl FoodBundle {
+    pub fn new(position: GridPosition, growth: u32) -> Self {
+        FoodBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Object,
+            food: Food { growth },
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.85, 0.2, 0.25),
+                    custom_size: Some(Vec2::splat(10.0 + 2.0 * growth.min(5) as f32)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for FoodBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.food.iter().map(|food| {
+            FoodBundle::new(
+                GridPosition::new(food.position[0], food.position[1]),
+                food.growth,
+            )
+        }));
+    }
+}
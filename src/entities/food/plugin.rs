@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::{stage::EntityProcessingStage, util::prelude::*, State};
+
+use super::{loadable::FoodBundle, systems::eat_food};
+
+pub struct FoodPlugin;
+
+impl Plugin for FoodPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<FoodBundle>()
+            .add_system_to_stage(EntityProcessingStage, eat_food.run_in_state(State::InLevel));
+    }
+}
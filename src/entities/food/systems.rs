@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::{
+    entities::dragon::{DragonHead, MaxLength},
+    grid::GridPosition,
+    movement::Movement,
+};
+
+use super::components::Food;
+
+pub fn eat_food(
+    mut commands: Commands,
+    mut dragons_query: Query<
+        (&Movement, Option<&mut MaxLength>),
+        (With<DragonHead>, Changed<Movement>),
+    >,
+    food_query: Query<(Entity, &GridPosition, &Food)>,
+) {
+    for (entity, position, food) in food_query.iter() {
+        let eaten_by = dragons_query
+            .iter_mut()
+            .find(|(movement, _)| movement.path.contains(position));
+
+        if let Some((_, max_length)) = eaten_by {
+            commands.entity(entity).despawn();
+
+            // Dragons without a maximum length already grow freely
+            if let Some(mut max_length) = max_length {
+                max_length.0 += food.growth;
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::Component;
+
+/// Opens every gate on its channel while a dragon is on top of it
+#[derive(Component)]
+pub struct PressurePlate {
+    pub channel: u32,
+}
+
+/// Opens every gate on its channel once a dragon has lit it
+#[derive(Component)]
+pub struct Brazier {
+    pub channel: u32,
+}
+
+#[derive(Component)]
+pub struct Gate {
+    pub channel: u32,
+}
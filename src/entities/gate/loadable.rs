@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    movement::{Blocker, Flammable},
+    util::prelude::*,
+};
+
+use super::components::{Brazier, Gate, PressurePlate};
+
+pub const GATE_OPEN_ALPHA: f32 = 0.25;
+pub const GATE_CLOSED_ALPHA: f32 = 0.9;
+pub const BRAZIER_UNLIT_COLOUR: Color = Color::rgb(0.25, 0.22, 0.2);
+
+pub fn channel_colour(channel: u32, alpha: f32) -> Color {
+    Color::hsla((channel * 97 % 360) as f32, 0.7, 0.45, alpha)
+}
+
+#[derive(Bundle)]
+pub struct PressurePlateBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    plate: PressurePlate,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+#[derive(Bundle)]
+pub struct BrazierBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    brazier: Brazier,
+    blocker: Blocker,
+    flammable: Flammable,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+#[derive(Bundle)]
+pub struct GateBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    gate: Gate,
+    blocker: Blocker,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl PressurePlateBundle {
+    pub fn new(position: GridPosition, channel: u32) -> Self {
+        PressurePlateBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Terrain,
+            plate: PressurePlate { channel },
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: channel_colour(channel, 0.8),
+                    custom_size: Some(Vec2::splat(20.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl BrazierBundle {
+    pub fn new(position: GridPosition, channel: u32) -> Self {
+        BrazierBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Object,
+            brazier: Brazier { channel },
+            blocker: Blocker,
+            flammable: Flammable::Ignited,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: BRAZIER_UNLIT_COLOUR,
+                    custom_size: Some(Vec2::splat(22.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl GateBundle {
+    pub fn new(position: GridPosition, channel: u32) -> Self {
+        GateBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Wall,
+            gate: Gate { channel },
+            blocker: Blocker,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: channel_colour(channel, GATE_CLOSED_ALPHA),
+                    custom_size: Some(Vec2::splat(30.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for PressurePlateBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.plates.iter().map(|plate| {
+            PressurePlateBundle::new(
+                GridPosition::new(plate.position[0], plate.position[1]),
+                plate.channel,
+            )
+        }));
+    }
+}
+
+impl Loadable<LevelConfig> for BrazierBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.braziers.iter().map(|brazier| {
+            BrazierBundle::new(
+                GridPosition::new(brazier.position[0], brazier.position[1]),
+                brazier.channel,
+            )
+        }));
+    }
+}
+
+impl Loadable<LevelConfig> for GateBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.gates.iter().map(|gate| {
+            GateBundle::new(
+                GridPosition::new(gate.position[0], gate.position[1]),
+                gate.channel,
+            )
+        }));
+    }
+}
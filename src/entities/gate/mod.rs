@@ -0,0 +1,6 @@
+mod components;
+mod loadable;
+mod plugin;
+mod systems;
+
+pub use self::plugin::GatePlugin;
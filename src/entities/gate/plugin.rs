@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::util::prelude::*;
+
+use super::{
+    loadable::{BrazierBundle, GateBundle, PressurePlateBundle},
+    systems::{light_braziers, update_gates},
+};
+
+pub struct GatePlugin;
+
+impl Plugin for GatePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<PressurePlateBundle>()
+            .register_loadable::<BrazierBundle>()
+            .register_loadable::<GateBundle>()
+            .add_system_to_stage(CoreStage::PostUpdate, update_gates)
+            .add_system_to_stage(CoreStage::PostUpdate, light_braziers);
+    }
+}
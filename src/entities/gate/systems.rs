@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    entities::dragon::{BodySegment, DragonHead},
+    grid::GridPosition,
+    movement::{Blocker, Lit},
+};
+
+use super::{
+    components::{Brazier, Gate, PressurePlate},
+    loadable::{channel_colour, GATE_CLOSED_ALPHA, GATE_OPEN_ALPHA},
+};
+
+pub fn update_gates(
+    mut commands: Commands,
+    occupants_query: Query<&GridPosition, Or<(With<DragonHead>, With<BodySegment>)>>,
+    plates_query: Query<(&GridPosition, &PressurePlate)>,
+    braziers_query: Query<&Brazier, With<Lit>>,
+    mut gates_query: Query<(Entity, &GridPosition, &Gate, &mut Sprite, Option<&Blocker>)>,
+) {
+    let occupied = occupants_query.iter().copied().collect::<HashSet<_>>();
+
+    let pressed_channels = plates_query
+        .iter()
+        .filter(|(position, _)| occupied.contains(position))
+        .map(|(_, plate)| plate.channel)
+        .chain(braziers_query.iter().map(|brazier| brazier.channel))
+        .collect::<HashSet<_>>();
+
+    for (entity, position, gate, mut sprite, blocker) in gates_query.iter_mut() {
+        let open = pressed_channels.contains(&gate.channel);
+
+        match (open, blocker.is_some()) {
+            (true, true) => {
+                commands.entity(entity).remove::<Blocker>();
+                sprite.color = channel_colour(gate.channel, GATE_OPEN_ALPHA);
+            }
+
+            // A gate can't close on top of a dragon, so it waits for the cell to clear
+            (false, false) if !occupied.contains(position) => {
+                commands.entity(entity).insert(Blocker);
+                sprite.color = channel_colour(gate.channel, GATE_CLOSED_ALPHA);
+            }
+
+            _ => (),
+        }
+    }
+}
+
+pub fn light_braziers(mut braziers_query: Query<(&Brazier, &mut Sprite), Added<Lit>>) {
+    for (brazier, mut sprite) in braziers_query.iter_mut() {
+        sprite.color = channel_colour(brazier.channel, 1.0);
+    }
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct Gem;
+
+/// One slot of the gem counter shown above the grid
+#[derive(Component)]
+pub struct GemIcon(pub u32);
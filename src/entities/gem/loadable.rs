@@ -0,0 +1,104 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    util::prelude::*,
+};
+
+use super::{
+    components::{Gem, GemIcon},
+    resources::GemCount,
+};
+
+pub const GEM_COLOUR: Color = Color::rgb(0.4, 0.95, 0.9);
+pub const MISSING_GEM_COLOUR: Color = Color::rgba(0.4, 0.95, 0.9, 0.25);
+
+fn gem_sprite(colour: Color) -> SpriteBundle {
+    SpriteBundle {
+        sprite: Sprite {
+            color: colour,
+            custom_size: Some(Vec2::splat(12.0)),
+            ..Default::default()
+        },
+        transform: Transform::from_rotation(Quat::from_rotation_z(PI / 4.0)),
+        ..Default::default()
+    }
+}
+
+#[derive(Bundle)]
+pub struct GemBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    gem: Gem,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+#[derive(Bundle)]
+pub struct GemIconBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    icon: GemIcon,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl GemBundle {
+    pub fn new(position: GridPosition) -> Self {
+        GemBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Object,
+            gem: Gem,
+            sprite: gem_sprite(GEM_COLOUR),
+        }
+    }
+}
+
+impl GemIconBundle {
+    pub fn new(position: GridPosition, index: u32) -> Self {
+        GemIconBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Object,
+            icon: GemIcon(index),
+            sprite: gem_sprite(MISSING_GEM_COLOUR),
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for GemBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.insert_resource(GemCount {
+            collected: 0,
+            total: scene.gems.len() as u32,
+            required: scene.require_all_gems,
+        });
+
+        world.spawn_batch(
+            scene
+                .gems
+                .iter()
+                .map(|gem| GemBundle::new(GridPosition::new(gem[0], gem[1]))),
+        );
+    }
+}
+
+impl Loadable<LevelConfig> for GemIconBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        // The counter sits in the row just above the top of the grid
+        let row = scene.size[1] as i32;
+
+        world.spawn_batch(
+            (0..scene.gems.len() as u32)
+                .map(move |index| GemIconBundle::new(GridPosition::new(index as i32, row), index)),
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod components;
+mod loadable;
+mod plugin;
+mod resources;
+mod systems;
+
+pub use self::{plugin::GemPlugin, resources::GemCount};
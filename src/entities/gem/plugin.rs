@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::{level::WinTimer, stage::EntityProcessingStage, util::prelude::*, State};
+
+use super::{
+    loadable::{GemBundle, GemIconBundle},
+    resources::GemCount,
+    systems::{celebrate_gems, collect_gems, update_gem_icons},
+};
+
+pub struct GemPlugin;
+
+impl Plugin for GemPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<GemBundle>()
+            .register_loadable::<GemIconBundle>()
+            .init_resource::<GemCount>()
+            .add_system_set_to_stage(
+                EntityProcessingStage,
+                SystemSet::new()
+                    .with_system(collect_gems.run_in_state(State::InLevel))
+                    .with_system(update_gem_icons)
+                    .with_system(
+                        celebrate_gems
+                            .run_if_resource_exists::<WinTimer>()
+                            .run_in_state(State::InLevel),
+                    ),
+            );
+    }
+}
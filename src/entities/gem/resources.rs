@@ -0,0 +1,15 @@
+use bevy::prelude::Resource;
+
+#[derive(Clone, Copy, Debug, Default, Resource)]
+pub struct GemCount {
+    pub collected: u32,
+    pub total: u32,
+    pub required: bool,
+}
+
+impl GemCount {
+    /// Whether enough gems have been collected for the level to be won
+    pub fn satisfied(&self) -> bool {
+        !self.required || self.collected >= self.total
+    }
+}
@@ -0,0 +1,60 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{
+    entities::dragon::DragonHead, grid::GridPosition, level::WinTimer, movement::Movement,
+};
+
+use super::{
+    components::{Gem, GemIcon},
+    loadable::{GEM_COLOUR, MISSING_GEM_COLOUR},
+    resources::GemCount,
+};
+
+pub fn collect_gems(
+    mut commands: Commands,
+    mut count: ResMut<GemCount>,
+    dragons_query: Query<&Movement, (With<DragonHead>, Changed<Movement>)>,
+    gems_query: Query<(Entity, &GridPosition), With<Gem>>,
+) {
+    for (entity, position) in gems_query.iter() {
+        if dragons_query
+            .iter()
+            .any(|movement| movement.path.contains(position))
+        {
+            commands.entity(entity).despawn();
+            count.collected += 1;
+        }
+    }
+}
+
+pub fn update_gem_icons(count: Res<GemCount>, mut icons_query: Query<(&GemIcon, &mut Sprite)>) {
+    if !count.is_changed() {
+        return;
+    }
+
+    for (icon, mut sprite) in icons_query.iter_mut() {
+        sprite.color = if icon.0 < count.collected {
+            GEM_COLOUR
+        } else {
+            MISSING_GEM_COLOUR
+        };
+    }
+}
+
+/// Bounce the collected gems in the counter along with the winning dragons, so the level's result
+/// shows how many of its gems were found
+pub fn celebrate_gems(
+    timer: Res<WinTimer>,
+    count: Res<GemCount>,
+    mut icons_query: Query<(&GemIcon, &mut Transform)>,
+) {
+    let bounce = (timer.0.percent() * PI * 3.0).sin().abs() * 0.3;
+
+    for (icon, mut transform) in icons_query.iter_mut() {
+        if icon.0 < count.collected {
+            transform.scale = Vec3::splat(1.0 + bounce);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// Kills any dragon head that moves onto it, even while sliding or being carried across
+#[derive(Component)]
+pub struct Hazard;
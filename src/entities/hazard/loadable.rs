@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    util::prelude::*,
+};
+
+use super::components::Hazard;
+
+#[derive(Bundle)]
+pub struct HazardBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    hazard: Hazard,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl HazardBundle {
+    pub fn new(position: GridPosition, colour: Color) -> Self {
+        HazardBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Terrain,
+            hazard: Hazard,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: colour,
+                    custom_size: Some(Vec2::splat(32.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn pit(position: GridPosition) -> Self {
+        Self::new(position, Color::rgb(0.05, 0.05, 0.08))
+    }
+
+    pub fn lava(position: GridPosition) -> Self {
+        Self::new(position, Color::rgb(0.95, 0.35, 0.1))
+    }
+}
+
+impl Loadable<LevelConfig> for HazardBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        let pits = scene
+            .pits
+            .iter()
+            .flat_map(|pit_range| pit_range.positions())
+            .map(HazardBundle::pit);
+
+        let lava = scene
+            .lava
+            .iter()
+            .flat_map(|lava_range| lava_range.positions())
+            .map(HazardBundle::lava);
+
+        world.spawn_batch(pits.chain(lava));
+    }
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::{
+    level::{LevelFailed, WinTimer},
+    stage::{MovementResolutionStage, PathExtension},
+    util::prelude::*,
+    State,
+};
+
+use super::{loadable::HazardBundle, systems::check_hazards};
+
+pub struct HazardPlugin;
+
+impl Plugin for HazardPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<HazardBundle>().add_system_to_stage(
+            MovementResolutionStage,
+            check_hazards
+                .run_unless_resource_exists::<LevelFailed>()
+                .run_unless_resource_exists::<WinTimer>()
+                .run_in_state(State::InLevel)
+                .after(PathExtension),
+        );
+    }
+}
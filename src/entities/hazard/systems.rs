@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    entities::dragon::DragonHead,
+    grid::GridPosition,
+    level::{FailReason, LevelFailed},
+    movement::Movement,
+};
+
+use super::components::Hazard;
+
+pub fn check_hazards(
+    mut commands: Commands,
+    hazards_query: Query<&GridPosition, With<Hazard>>,
+    mut heads_query: Query<(&mut Movement, &mut Visibility), (With<DragonHead>, Changed<Movement>)>,
+) {
+    let hazards = hazards_query.iter().copied().collect::<HashSet<_>>();
+    let mut lost = false;
+
+    for (mut movement, mut visibility) in heads_query.iter_mut() {
+        // The dragon is lost on the first hazard it crosses, so it goes no further
+        if let Some(index) = movement.path.iter().position(|cell| hazards.contains(cell)) {
+            movement.path.truncate(index + 1);
+            visibility.is_visible = false;
+            lost = true;
+        }
+    }
+
+    if lost {
+        commands.insert_resource(LevelFailed(FailReason::DragonLost));
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    movement::{Flammable, Slippery},
+    util::prelude::*,
+};
+
+#[derive(Bundle)]
+pub struct IceBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    slippery: Slippery,
+    flammable: Flammable,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl IceBundle {
+    pub fn new(position: GridPosition) -> Self {
+        IceBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Terrain,
+            slippery: Slippery,
+            flammable: Flammable::Consumed,
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgba(0.7, 0.9, 1.0, 0.6),
+                    custom_size: Some(Vec2::splat(32.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for IceBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        let ice = scene
+            .ice
+            .iter()
+            .flat_map(|ice_range| ice_range.positions())
+            .collect::<HashSet<_>>();
+
+        world.spawn_batch(ice.into_iter().map(IceBundle::new));
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use crate::util::prelude::*;
+
+use super::loadable::IceBundle;
+
+pub struct IcePlugin;
+
+impl Plugin for IcePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<IceBundle>();
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    movement::{Door, Key, KeyColour},
+    util::prelude::*,
+};
+
+fn key_colour(colour: KeyColour) -> Color {
+    match colour {
+        KeyColour::Red => Color::rgb(0.85, 0.2, 0.2),
+        KeyColour::Green => Color::rgb(0.3, 0.8, 0.3),
+        KeyColour::Blue => Color::rgb(0.25, 0.4, 0.9),
+        KeyColour::Yellow => Color::rgb(0.95, 0.85, 0.25),
+    }
+}
+
+#[derive(Bundle)]
+pub struct KeyBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    key: Key,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+#[derive(Bundle)]
+pub struct DoorBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    door: Door,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl KeyBundle {
+    pub fn new(position: GridPosition, colour: KeyColour) -> Self {
+        KeyBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Object,
+            key: Key { colour },
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: key_colour(colour),
+                    custom_size: Some(Vec2::new(14.0, 8.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl DoorBundle {
+    pub fn new(position: GridPosition, colour: KeyColour) -> Self {
+        DoorBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Wall,
+            door: Door { colour },
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color: key_colour(colour),
+                    custom_size: Some(Vec2::splat(30.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for KeyBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.keys.iter().map(|key| {
+            KeyBundle::new(
+                GridPosition::new(key.position[0], key.position[1]),
+                key.colour,
+            )
+        }));
+    }
+}
+
+impl Loadable<LevelConfig> for DoorBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.doors.iter().map(|door| {
+            DoorBundle::new(
+                GridPosition::new(door.position[0], door.position[1]),
+                door.colour,
+            )
+        }));
+    }
+}
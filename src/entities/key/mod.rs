@@ -0,0 +1,4 @@
+mod loadable;
+mod plugin;
+
+pub use self::plugin::KeyPlugin;
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+use crate::util::prelude::*;
+
+use super::loadable::{DoorBundle, KeyBundle};
+
+pub struct KeyPlugin;
+
+impl Plugin for KeyPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<KeyBundle>()
+            .register_loadable::<DoorBundle>();
+    }
+}
@@ -3,15 +3,30 @@ use bevy_asset_loader::prelude::*;
 
 use crate::util::prelude::*;
 
+pub mod arrow;
 pub mod background;
+pub mod burnable;
 pub mod dragon;
+pub mod egg;
+pub mod enemy;
+pub mod food;
+pub mod gate;
+pub mod gem;
+pub mod hazard;
+pub mod ice;
+pub mod key;
+pub mod portal;
 pub mod wall;
 
 pub struct EntityPlugins;
 
 impl<State: StateData> AssetProvider<State> for EntityPlugins {
     fn provide(&self, state: LoadingState<State>) -> LoadingState<State> {
-        state.with_asset_provider(dragon::DragonPlugin)
+        state
+            .with_asset_provider(background::BackgroundPlugin)
+            .with_asset_provider(wall::WallPlugin)
+            .with_asset_provider(arrow::ArrowPlugin)
+            .with_asset_provider(dragon::DragonPlugin)
     }
 }
 
@@ -19,7 +34,18 @@ impl PluginGroup for EntityPlugins {
     fn build(self) -> PluginGroupBuilder {
         PluginGroupBuilder::start::<Self>()
             .add(background::BackgroundPlugin)
+            .add(ice::IcePlugin)
+            .add(hazard::HazardPlugin)
+            .add(portal::PortalPlugin)
             .add(wall::WallPlugin)
+            .add(burnable::BurnablePlugin)
+            .add(gate::GatePlugin)
+            .add(key::KeyPlugin)
+            .add(gem::GemPlugin)
+            .add(food::FoodPlugin)
+            .add(enemy::EnemyPlugin)
+            .add(egg::EggPlugin)
+            .add(arrow::ArrowPlugin)
             .add(dragon::DragonPlugin)
     }
 }
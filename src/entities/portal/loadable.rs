@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::{
+    grid::{GridPosition, RenderLayer},
+    level::{LevelComponent, LevelConfig},
+    movement::Portal,
+    util::prelude::*,
+};
+
+#[derive(Bundle)]
+pub struct PortalBundle {
+    component: LevelComponent,
+    position: GridPosition,
+    layer: RenderLayer,
+    portal: Portal,
+
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl PortalBundle {
+    pub fn new(position: GridPosition, partner: GridPosition, id: u32) -> Self {
+        PortalBundle {
+            component: LevelComponent,
+            position,
+            layer: RenderLayer::Terrain,
+            portal: Portal { partner },
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    // Spread the hues out so that each pair is easy to tell apart
+                    color: Color::hsla((id * 137 % 360) as f32, 0.8, 0.6, 0.8),
+                    custom_size: Some(Vec2::splat(24.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Loadable<LevelConfig> for PortalBundle {
+    fn from_scene(world: &mut World, scene: &LevelConfig) {
+        world.spawn_batch(scene.portals.iter().flat_map(|portal| {
+            let [a, b] = portal.ends.map(|end| GridPosition::new(end[0], end[1]));
+
+            [
+                PortalBundle::new(a, b, portal.id),
+                PortalBundle::new(b, a, portal.id),
+            ]
+        }));
+    }
+}
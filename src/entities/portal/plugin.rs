@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use crate::util::prelude::*;
+
+use super::loadable::PortalBundle;
+
+pub struct PortalPlugin;
+
+impl Plugin for PortalPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_loadable::<PortalBundle>();
+    }
+}
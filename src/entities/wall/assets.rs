@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[derive(AssetCollection, Resource)]
+pub struct WallAssets {
+    #[asset(texture_atlas(tile_size_x = 16., tile_size_y = 16., columns = 5, rows = 1))]
+    #[asset(path = "tiles/wall.png")]
+    pub atlas: Handle<TextureAtlas>,
+}
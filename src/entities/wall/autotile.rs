@@ -0,0 +1,109 @@
+use std::{collections::HashSet, f32::consts::PI};
+
+use bevy::prelude::*;
+
+use crate::{
+    direction::Direction,
+    grid::{GridPosition, GridShape},
+};
+
+const FILL: usize = 0;
+const OUTER_CORNER: usize = 1;
+const FIRST_EDGE: usize = 2;
+const SECOND_EDGE: usize = 3;
+const INNER_CORNER: usize = 4;
+
+// Each wall cell is drawn as four quadrants, clockwise from the top left. The atlas only holds
+// top left quadrants, so the others reuse them rotated, with their neighbours rotated to match.
+const QUADRANTS: [(Direction, Direction, f32, f32); 4] = [
+    (Direction::Left, Direction::Up, -1.0, 1.0),
+    (Direction::Up, Direction::Right, 1.0, 1.0),
+    (Direction::Right, Direction::Down, 1.0, -1.0),
+    (Direction::Down, Direction::Left, -1.0, -1.0),
+];
+
+pub fn wall_quadrants(
+    walls: &HashSet<GridPosition>,
+    position: GridPosition,
+    quadrant_size: f32,
+    shape: GridShape,
+) -> impl Iterator<Item = (usize, Transform)> + '_ {
+    // Hex cells don't split into square quadrants, so draw them solid
+    let is_wall = move |position| shape == GridShape::Hex || walls.contains(&position);
+
+    QUADRANTS
+        .iter()
+        .enumerate()
+        .map(move |(quadrant, &(first, second, x, y))| {
+            let has_first = is_wall(position.apply_direction(first));
+            let has_second = is_wall(position.apply_direction(second));
+            let has_diagonal = is_wall(position.apply_direction(first).apply_direction(second));
+
+            let index = match (has_first, has_second, has_diagonal) {
+                (false, false, _) => OUTER_CORNER,
+                (true, false, _) => FIRST_EDGE,
+                (false, true, _) => SECOND_EDGE,
+                (true, true, false) => INNER_CORNER,
+                (true, true, true) => FILL,
+            };
+
+            let transform = Transform {
+                translation: Vec3::new(x * quadrant_size / 2.0, y * quadrant_size / 2.0, 0.0),
+                rotation: Quat::from_rotation_z(-(PI / 2.0) * quadrant as f32),
+                ..Default::default()
+            };
+
+            (index, transform)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indices(walls: &[(i32, i32)], shape: GridShape) -> Vec<usize> {
+        let walls = walls
+            .iter()
+            .map(|&(x, y)| GridPosition::new(x, y))
+            .collect::<HashSet<_>>();
+
+        wall_quadrants(&walls, GridPosition::new(0, 0), 32.0, shape)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    #[test]
+    fn lone_wall_has_outer_corners() {
+        assert_eq!(indices(&[(0, 0)], GridShape::Square), [OUTER_CORNER; 4]);
+    }
+
+    #[test]
+    fn surrounded_wall_is_filled() {
+        let walls = (-1..=1)
+            .flat_map(|x| (-1..=1).map(move |y| (x, y)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(indices(&walls, GridShape::Square), [FILL; 4]);
+    }
+
+    #[test]
+    fn horizontal_run_has_edges() {
+        assert_eq!(
+            indices(&[(-1, 0), (0, 0), (1, 0)], GridShape::Square),
+            [FIRST_EDGE, SECOND_EDGE, FIRST_EDGE, SECOND_EDGE]
+        );
+    }
+
+    #[test]
+    fn missing_diagonal_is_an_inner_corner() {
+        assert_eq!(
+            indices(&[(-1, 0), (0, 0), (0, 1)], GridShape::Square)[0],
+            INNER_CORNER
+        );
+    }
+
+    #[test]
+    fn hex_walls_are_filled() {
+        assert_eq!(indices(&[(0, 0)], GridShape::Hex), [FILL; 4]);
+    }
+}
@@ -1,20 +1,25 @@
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 
 use crate::{
-    grid::GridPosition,
+    grid::{GridPosition, RenderLayer},
     level::{LevelComponent, LevelConfig},
     movement::Blocker,
     util::prelude::*,
 };
 
+use super::{assets::WallAssets, autotile::wall_quadrants};
+
 #[derive(Bundle)]
 pub struct WallBundle {
     component: LevelComponent,
     position: GridPosition,
+    layer: RenderLayer,
     blocker: Blocker,
 
     #[bundle]
-    sprite: SpriteBundle,
+    spatial: SpatialBundle,
 }
 
 impl WallBundle {
@@ -22,29 +27,38 @@ impl WallBundle {
         WallBundle {
             component: LevelComponent,
             position,
+            layer: RenderLayer::Wall,
             blocker: Blocker,
-            sprite: SpriteBundle {
-                sprite: Sprite {
-                    color: Color::rgba(0.7, 0.5, 0.5, 0.8),
-                    ..Default::default()
-                },
-                transform: Transform {
-                    scale: Vec3::new(31.0, 31.0, 31.0),
-                    ..Default::default()
-                },
-                ..Default::default()
-            },
+            spatial: SpatialBundle::default(),
         }
     }
 }
 
 impl Loadable<LevelConfig> for WallBundle {
     fn from_scene(world: &mut World, scene: &LevelConfig) {
-        world.spawn_batch(scene.walls.iter().flat_map(|wall_range| {
-            (wall_range.from[0]..=wall_range.to[0]).flat_map(|x| {
-                (wall_range.from[1]..=wall_range.to[1])
-                    .map(move |y| WallBundle::new(GridPosition::new(x, y)))
-            })
-        }));
+        let walls = scene
+            .walls
+            .iter()
+            .flat_map(|wall_range| wall_range.positions())
+            .collect::<HashSet<_>>();
+
+        world.resource_scope(|world, assets: Mut<WallAssets>| {
+            for position in &walls {
+                world
+                    .spawn(WallBundle::new(*position))
+                    .with_children(|parent| {
+                        for (index, transform) in
+                            wall_quadrants(&walls, *position, 16.0, scene.shape)
+                        {
+                            parent.spawn(SpriteSheetBundle {
+                                sprite: TextureAtlasSprite::new(index),
+                                texture_atlas: assets.atlas.clone(),
+                                transform,
+                                ..Default::default()
+                            });
+                        }
+                    });
+            }
+        });
     }
 }
@@ -1,3 +1,5 @@
+mod assets;
+mod autotile;
 mod loadable;
 mod plugin;
 
@@ -1,11 +1,18 @@
-use bevy::prelude::*;
+use bevy::{ecs::schedule::StateData, prelude::*};
+use bevy_asset_loader::prelude::*;
 
 use crate::util::prelude::*;
 
-use super::loadable::WallBundle;
+use super::{assets::WallAssets, loadable::WallBundle};
 
 pub struct WallPlugin;
 
+impl<State: StateData> AssetProvider<State> for WallPlugin {
+    fn provide(&self, state: LoadingState<State>) -> LoadingState<State> {
+        state.with_collection::<WallAssets>()
+    }
+}
+
 impl Plugin for WallPlugin {
     fn build(&self, app: &mut App) {
         app.register_loadable::<WallBundle>();
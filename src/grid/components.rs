@@ -1,4 +1,5 @@
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Vec2};
+use serde::Deserialize;
 
 use crate::direction::Direction;
 
@@ -8,24 +9,127 @@ pub struct GridSize {
     pub height: u32,
 }
 
+/// Which pairs of opposite edges lead into each other
+#[derive(Deserialize, Component, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum GridWrap {
+    #[default]
+    None,
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+/// The shape of each cell. Hex grids use pointy-topped cells in axial coordinates, so a
+/// rectangular `GridSize` lays out as a rhombus leaning to the right.
+#[derive(Deserialize, Component, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum GridShape {
+    #[default]
+    Square,
+    Hex,
+}
+
 #[derive(Component, Clone, Copy, Debug)]
 pub struct GridScale {
     pub width: f32,
     pub height: f32,
 }
 
-#[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct GridPosition {
     pub x: i32,
     pub y: i32,
 }
 
+/// Draw order of grid entities, from back to front
+#[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Debug)]
+pub enum RenderLayer {
+    #[default]
+    Floor,
+    Terrain,
+    Wall,
+    Object,
+    Body,
+    Head,
+    Overlay,
+}
+
 impl GridSize {
     pub fn new(width: u32, height: u32) -> Self {
         Self { width, height }
     }
 }
 
+impl GridWrap {
+    pub fn horizontal(self) -> bool {
+        matches!(self, GridWrap::Horizontal | GridWrap::Both)
+    }
+
+    pub fn vertical(self) -> bool {
+        matches!(self, GridWrap::Vertical | GridWrap::Both)
+    }
+}
+
+impl GridShape {
+    /// The directions a head can face, anticlockwise from `Right`
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            GridShape::Square => &[
+                Direction::Right,
+                Direction::Up,
+                Direction::Left,
+                Direction::Down,
+            ],
+            GridShape::Hex => &[
+                Direction::Right,
+                Direction::UpRight,
+                Direction::UpLeft,
+                Direction::Left,
+                Direction::DownLeft,
+                Direction::DownRight,
+            ],
+        }
+    }
+
+    /// Distance between the centres of neighbouring rows
+    pub fn row_height(self, scale: GridScale) -> f32 {
+        match self {
+            GridShape::Square => scale.height,
+            GridShape::Hex => scale.height * 3f32.sqrt() / 2.0,
+        }
+    }
+
+    /// Offset of a cell's centre from the centre of the bottom left cell
+    pub fn offset(self, position: GridPosition, scale: GridScale) -> Vec2 {
+        let shear = match self {
+            GridShape::Square => 0.0,
+            GridShape::Hex => position.y as f32 / 2.0,
+        };
+
+        Vec2::new(
+            (position.x as f32 + shear) * scale.width,
+            position.y as f32 * self.row_height(scale),
+        )
+    }
+
+    /// Distance between the centres of the bottom left and top right cells
+    pub fn span(self, size: GridSize, scale: GridScale) -> Vec2 {
+        self.offset(
+            GridPosition::new(size.width as i32 - 1, size.height as i32 - 1),
+            scale,
+        )
+    }
+
+    /// Size in pixels of the whole grid
+    pub fn extent(self, size: GridSize, scale: GridScale) -> Vec2 {
+        let cell_height = match self {
+            GridShape::Square => scale.height,
+            GridShape::Hex => scale.height * 2.0 / 3f32.sqrt(),
+        };
+
+        self.span(size, scale) + Vec2::new(scale.width, cell_height)
+    }
+}
+
 impl GridScale {
     pub fn new(width: f32, height: f32) -> Self {
         Self { width, height }
@@ -36,6 +140,12 @@ impl GridScale {
     }
 }
 
+impl RenderLayer {
+    pub fn z(self) -> f32 {
+        self as u8 as f32
+    }
+}
+
 impl GridPosition {
     pub fn new(x: i32, y: i32) -> Self {
         Self { x, y }
@@ -49,4 +159,33 @@ impl GridPosition {
             y: self.y + dy,
         }
     }
+
+    /// Like `apply_direction`, but coming back in on the opposite side of any wrapping edges
+    pub fn apply_direction_wrapped(
+        &self,
+        direction: Direction,
+        size: GridSize,
+        wrap: GridWrap,
+    ) -> Self {
+        let mut position = self.apply_direction(direction);
+
+        if wrap.horizontal() {
+            position.x = position.x.rem_euclid(size.width as i32);
+        }
+
+        if wrap.vertical() {
+            position.y = position.y.rem_euclid(size.height as i32);
+        }
+
+        position
+    }
+
+    /// The direction of a neighbouring cell, if `other` is one
+    pub fn direction_to(&self, other: GridPosition, shape: GridShape) -> Option<Direction> {
+        shape
+            .directions()
+            .iter()
+            .copied()
+            .find(|direction| self.apply_direction(*direction) == other)
+    }
 }
@@ -5,12 +5,14 @@ use crate::{
     util::prelude::*,
 };
 
-use super::components::{GridScale, GridSize};
+use super::components::{GridScale, GridShape, GridSize, GridWrap};
 
 #[derive(Bundle, Clone, Copy)]
 pub struct GridBundle {
     size: GridSize,
     scale: GridScale,
+    wrap: GridWrap,
+    shape: GridShape,
 }
 
 impl Loadable<LevelConfig> for GridBundle {
@@ -19,6 +21,8 @@ impl Loadable<LevelConfig> for GridBundle {
             .spawn(GridBundle {
                 size: GridSize::new(level.size[0], level.size[1]),
                 scale: GridScale::new_square(32.0),
+                wrap: level.wrap,
+                shape: level.shape,
             })
             .insert(LevelComponent);
     }
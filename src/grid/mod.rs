@@ -4,6 +4,6 @@ mod plugin;
 mod systems;
 
 pub use self::{
-    components::{GridPosition, GridScale, GridSize},
+    components::{GridPosition, GridScale, GridShape, GridSize, GridWrap, RenderLayer},
     plugin::GridPlugin,
 };
@@ -8,7 +8,30 @@ pub struct LevelAssets {
     #[cfg_attr(
         target_family = "wasm",
         asset(
-            paths("levels/1.level", "levels/2.level", "levels/3.level"),
+            paths(
+                "levels/1.level",
+                "levels/2.level",
+                "levels/3.level",
+                "levels/4.level",
+                "levels/5.level",
+                "levels/6.level",
+                "levels/7.level",
+                "levels/8.level",
+                "levels/9.level",
+                "levels/10.level",
+                "levels/11.level",
+                "levels/12.level",
+                "levels/13.level",
+                "levels/14.level",
+                "levels/15.level",
+                "levels/16.level",
+                "levels/17.level",
+                "levels/18.level",
+                "levels/19.level",
+                "levels/20.level",
+                "levels/21.level",
+                "levels/22.level"
+            ),
             collection(typed)
         )
     )]
@@ -1,26 +1,302 @@
+use std::collections::HashSet;
+
 use bevy::reflect::TypeUuid;
 use serde::Deserialize;
 
-use crate::direction::Direction;
+use crate::{
+    direction::Direction,
+    grid::{GridPosition, GridShape, GridSize, GridWrap},
+    movement::{KeyColour, TurnMode},
+};
 
 #[derive(Deserialize, Clone, Copy)]
 pub struct DragonConfig {
     pub position: [i32; 2],
     pub direction: Direction,
+
+    /// Dragons only win by meeting a partner from the same pair
+    #[serde(default)]
+    pub pair: u32,
+
+    /// Longest the body can grow, if the tail should retract as the head moves
+    #[serde(default)]
+    pub max_length: Option<u32>,
 }
 
 #[derive(Deserialize, Clone, Copy)]
-pub struct WallConfig {
+pub struct PortalConfig {
+    pub id: u32,
+    pub ends: [[i32; 2]; 2],
+}
+
+/// A cell linked to every other cell on the same channel
+#[derive(Deserialize, Clone, Copy)]
+pub struct ChannelConfig {
+    pub channel: u32,
+    pub position: [i32; 2],
+}
+
+/// A tile pointing in a direction
+#[derive(Deserialize, Clone, Copy)]
+pub struct ArrowConfig {
+    pub direction: Direction,
+    pub position: [i32; 2],
+}
+
+/// A key, or a door opened by one
+#[derive(Deserialize, Clone, Copy)]
+pub struct KeyConfig {
+    pub colour: KeyColour,
+    pub position: [i32; 2],
+}
+
+/// Food letting the dragon that eats it grow longer
+#[derive(Deserialize, Clone, Copy)]
+pub struct FoodConfig {
+    pub position: [i32; 2],
+    pub growth: u32,
+}
+
+/// An enemy walking a looping patrol, starting from the first cell. It waits in place while the
+/// next cell is blocked or covered by a dragon's body.
+#[derive(Deserialize, Clone)]
+pub struct EnemyConfig {
+    pub path: Vec<[i32; 2]>,
+}
+
+/// An inclusive rectangle of cells
+#[derive(Deserialize, Clone, Copy)]
+pub struct RegionConfig {
     pub from: [i32; 2],
     pub to: [i32; 2],
 }
 
-#[derive(Deserialize, TypeUuid, Clone)]
+#[derive(Deserialize, TypeUuid, Clone, Default)]
 #[uuid = "8d84e066-5bad-49f1-85d1-60788779f1d5"]
 pub struct LevelConfig {
     pub size: [u32; 2],
     pub dragons: Vec<DragonConfig>,
 
     #[serde(default)]
-    pub walls: Vec<WallConfig>,
+    pub shape: GridShape,
+
+    #[serde(default)]
+    pub wrap: GridWrap,
+
+    #[serde(default)]
+    pub turn_mode: TurnMode,
+
+    /// Enables the backwards action, which steps a head away from the way it faces and retracts
+    /// the body when the step retraces its trail
+    #[serde(default)]
+    pub allow_reverse: bool,
+
+    /// Direction dragons fall in after each move until something holds them up
+    #[serde(default)]
+    pub gravity: Option<Direction>,
+
+    /// Cells cut out of the level, with no floor and nothing able to enter them
+    #[serde(default)]
+    pub void: Vec<RegionConfig>,
+
+    #[serde(default)]
+    pub walls: Vec<RegionConfig>,
+
+    #[serde(default)]
+    pub ice: Vec<RegionConfig>,
+
+    #[serde(default)]
+    pub burnable: Vec<RegionConfig>,
+
+    #[serde(default)]
+    pub portals: Vec<PortalConfig>,
+
+    #[serde(default)]
+    pub plates: Vec<ChannelConfig>,
+
+    #[serde(default)]
+    pub gates: Vec<ChannelConfig>,
+
+    #[serde(default)]
+    pub braziers: Vec<ChannelConfig>,
+
+    #[serde(default)]
+    pub keys: Vec<KeyConfig>,
+
+    #[serde(default)]
+    pub doors: Vec<KeyConfig>,
+
+    #[serde(default)]
+    pub one_way: Vec<ArrowConfig>,
+
+    #[serde(default)]
+    pub conveyors: Vec<ArrowConfig>,
+
+    #[serde(default)]
+    pub pits: Vec<RegionConfig>,
+
+    #[serde(default)]
+    pub lava: Vec<RegionConfig>,
+
+    #[serde(default)]
+    pub gems: Vec<[i32; 2]>,
+
+    #[serde(default)]
+    pub require_all_gems: bool,
+
+    #[serde(default)]
+    pub food: Vec<FoodConfig>,
+
+    #[serde(default)]
+    pub enemies: Vec<EnemyConfig>,
+
+    /// Dragons waiting to hatch once another dragon's head faces them
+    #[serde(default)]
+    pub eggs: Vec<DragonConfig>,
+}
+
+impl LevelConfig {
+    /// Check every direction in the level exists on its grid shape, and every enemy patrol only
+    /// steps between neighbouring cells
+    pub fn validate(&self) -> Result<(), String> {
+        let directions = self.shape.directions();
+
+        for dragon in self.dragons.iter().chain(&self.eggs) {
+            if !directions.contains(&dragon.direction) {
+                return Err(format!(
+                    "dragon at {:?} faces {:?}, which a {:?} grid doesn't have",
+                    dragon.position, dragon.direction, self.shape
+                ));
+            }
+        }
+
+        for arrow in self.one_way.iter().chain(&self.conveyors) {
+            if !directions.contains(&arrow.direction) {
+                return Err(format!(
+                    "tile at {:?} points {:?}, which a {:?} grid doesn't have",
+                    arrow.position, arrow.direction, self.shape
+                ));
+            }
+        }
+
+        if let Some(gravity) = self.gravity {
+            if !directions.contains(&gravity) {
+                return Err(format!(
+                    "gravity points {:?}, which a {:?} grid doesn't have",
+                    gravity, self.shape
+                ));
+            }
+        }
+
+        let size = GridSize::new(self.size[0], self.size[1]);
+
+        for enemy in &self.enemies {
+            if enemy.path.is_empty() {
+                return Err("an enemy has an empty patrol".into());
+            }
+
+            // Patrols loop, so the last cell has to lead back to the first
+            let cells = enemy
+                .path
+                .iter()
+                .map(|cell| GridPosition::new(cell[0], cell[1]))
+                .collect::<Vec<_>>();
+
+            for (from, to) in cells.iter().zip(cells.iter().cycle().skip(1)) {
+                let neighbours = from == to
+                    || directions.iter().any(|direction| {
+                        from.apply_direction_wrapped(*direction, size, self.wrap) == *to
+                    });
+
+                if !neighbours {
+                    return Err(format!(
+                        "an enemy patrol steps from {:?} to {:?}, which aren't neighbours",
+                        from, to
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn void_cells(&self) -> HashSet<GridPosition> {
+        self.void
+            .iter()
+            .flat_map(|region| region.positions())
+            .collect()
+    }
+}
+
+impl RegionConfig {
+    pub fn positions(self) -> impl Iterator<Item = GridPosition> {
+        (self.from[0]..=self.to[0])
+            .flat_map(move |x| (self.from[1]..=self.to[1]).map(move |y| GridPosition::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dragon(direction: Direction) -> DragonConfig {
+        DragonConfig {
+            position: [0, 0],
+            direction,
+            pair: 0,
+            max_length: None,
+        }
+    }
+
+    #[test]
+    fn square_levels_accept_straight_directions() {
+        let level = LevelConfig {
+            size: [3, 3],
+            dragons: vec![dragon(Direction::Up), dragon(Direction::Down)],
+            gravity: Some(Direction::Down),
+            ..Default::default()
+        };
+
+        assert!(level.validate().is_ok());
+    }
+
+    #[test]
+    fn square_levels_reject_hex_directions() {
+        let level = LevelConfig {
+            size: [3, 3],
+            dragons: vec![dragon(Direction::UpRight)],
+            ..Default::default()
+        };
+
+        assert!(level.validate().is_err());
+    }
+
+    #[test]
+    fn hex_levels_reject_straight_gravity() {
+        let level = LevelConfig {
+            size: [3, 3],
+            dragons: vec![dragon(Direction::Right)],
+            shape: GridShape::Hex,
+            gravity: Some(Direction::Down),
+            ..Default::default()
+        };
+
+        assert!(level.validate().is_err());
+    }
+
+    #[test]
+    fn patrols_reject_steps_between_distant_cells() {
+        let enemy = |path: Vec<[i32; 2]>| LevelConfig {
+            size: [3, 3],
+            dragons: vec![dragon(Direction::Up)],
+            enemies: vec![EnemyConfig { path }],
+            ..Default::default()
+        };
+
+        assert!(enemy(vec![[0, 0], [1, 0], [1, 1], [0, 1]])
+            .validate()
+            .is_ok());
+        assert!(enemy(vec![[0, 0], [2, 0]]).validate().is_err());
+        assert!(enemy(vec![]).validate().is_err());
+    }
 }
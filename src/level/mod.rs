@@ -5,7 +5,11 @@ mod plugin;
 mod resources;
 mod switcher;
 mod systems;
+mod transition;
 
 pub use {
-    components::LevelComponent, config::LevelConfig, plugin::LevelPlugin, resources::WinTimer,
+    components::LevelComponent,
+    config::{DragonConfig, LevelConfig},
+    plugin::LevelPlugin,
+    resources::{FailReason, FallTimer, LevelFailed, WinTimer},
 };
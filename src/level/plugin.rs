@@ -10,7 +10,8 @@ use super::{
     config::LevelConfig,
     resources::{CurrentLevel, WinTimer},
     switcher::LevelSwitcherPlugin,
-    systems::{check_win_timer, load_level, unload_level},
+    systems::{check_win_timer, load_level, report_failure, unload_level},
+    transition::LevelTransitionPlugin,
 };
 
 pub struct LevelPlugin;
@@ -25,13 +26,15 @@ impl Plugin for LevelPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(JsonAssetPlugin::<LevelConfig>::new(&["level"]))
             .add_plugin(LevelSwitcherPlugin)
+            .add_plugin(LevelTransitionPlugin)
             .add_enter_system(State::LevelLoading, load_level)
-            .add_exit_system(State::InLevel, unload_level)
+            .add_exit_system(State::LevelOutro, unload_level)
             .add_system(
                 check_win_timer
                     .run_if_resource_exists::<WinTimer>()
                     .run_in_state(State::InLevel),
             )
+            .add_system(report_failure.run_in_state(State::InLevel))
             .insert_resource(CurrentLevel(0));
     }
 }
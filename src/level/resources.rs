@@ -1,7 +1,36 @@
+use std::fmt;
+
 use bevy::{prelude::Resource, time::Timer};
 
 #[derive(Clone, Debug, Resource)]
 pub struct WinTimer(pub Timer);
 
+/// Why a level can no longer be won
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FailReason {
+    DragonLost,
+    DragonCaught,
+    NoMovesLeft,
+}
+
+/// A dragon has died, so the level can only be restarted or skipped
+#[derive(Clone, Debug, Resource)]
+pub struct LevelFailed(pub FailReason);
+
+/// Dragons are still falling after a move, paced one cell per tick
+#[derive(Clone, Debug, Resource)]
+pub struct FallTimer(pub Timer);
+
+/// Index of the level being played, which wraps around the available levels
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Resource)]
 pub struct CurrentLevel(pub usize);
+
+impl fmt::Display for FailReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            FailReason::DragonLost => "A dragon was lost",
+            FailReason::DragonCaught => "A knight caught a dragon",
+            FailReason::NoMovesLeft => "No moves left",
+        })
+    }
+}
@@ -24,7 +24,10 @@ impl LevelSwitcherBundle {
             switcher: LevelSwitcher,
             component: LevelComponent,
             input_manager: InputManagerBundle::<Action> {
-                input_map: InputMap::new([(KeyCode::Space, Action::SwitchLevel)]),
+                input_map: InputMap::new([
+                    (KeyCode::Space, Action::SwitchLevel),
+                    (KeyCode::R, Action::RestartLevel),
+                ]),
                 ..Default::default()
             },
         }
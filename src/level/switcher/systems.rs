@@ -2,19 +2,27 @@ use bevy::prelude::*;
 use iyes_loopless::prelude::*;
 use leafwing_input_manager::prelude::*;
 
-use crate::{action::Action, State};
+use crate::{action::Action, level::resources::CurrentLevel, State};
 
 use super::components::LevelSwitcher;
 
 pub fn switch_level(
     mut commands: Commands,
+    mut current: ResMut<CurrentLevel>,
     query: Query<&ActionState<Action>, With<LevelSwitcher>>,
 ) {
     let action = query.single();
 
     for action in action.get_just_released() {
-        if let Action::SwitchLevel = action {
-            commands.insert_resource(NextState(State::LevelLoading));
+        match action {
+            Action::SwitchLevel => {
+                current.0 += 1;
+                commands.insert_resource(NextState(State::LevelOutro));
+            }
+            Action::RestartLevel => {
+                commands.insert_resource(NextState(State::LevelOutro));
+            }
+            _ => (),
         }
     }
 }
@@ -1,46 +1,77 @@
 use bevy::prelude::*;
 use iyes_loopless::prelude::*;
 
-use crate::{util, State};
+use crate::{movement::NoMovesLeft, util, State};
 
 use super::{
     assets::LevelAssets,
     components::LevelComponent,
     config::LevelConfig,
-    resources::{CurrentLevel, WinTimer},
+    resources::{CurrentLevel, FailReason, FallTimer, LevelFailed, WinTimer},
 };
 
 pub fn load_level(world: &mut World) {
     world.resource_scope(|world, config: Mut<LevelAssets>| {
-        let index = world.resource_scope(|_, mut current: Mut<CurrentLevel>| {
-            let index = current.0 % config.levels.len();
-            current.0 += 1;
-            index
-        });
+        let index = world.resource::<CurrentLevel>().0 % config.levels.len();
 
         world.resource_scope(|world, assets: Mut<Assets<LevelConfig>>| {
             let handle = &config.levels[index];
             let level = assets.get(handle).unwrap();
 
-            util::load_loadables(world, level);
+            match level.validate() {
+                Ok(()) => {
+                    util::load_loadables(world, level);
+                    world.insert_resource(NextState(State::LevelIntro));
+                }
+                Err(problem) => {
+                    error!("Skipping level {}: {}", index + 1, problem);
+                    world.resource_mut::<CurrentLevel>().0 += 1;
+                    world.insert_resource(NextState(State::LevelOutro));
+                }
+            }
         });
     });
-
-    world.insert_resource(NextState(State::InLevel));
 }
 
-pub fn check_win_timer(mut commands: Commands, time: Res<Time>, mut timer: ResMut<WinTimer>) {
+pub fn check_win_timer(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: ResMut<WinTimer>,
+    mut current: ResMut<CurrentLevel>,
+) {
     timer.0.tick(time.delta());
 
     if timer.0.just_finished() {
-        commands.insert_resource(NextState(State::LevelLoading));
+        current.0 += 1;
+        commands.insert_resource(NextState(State::LevelOutro));
     }
 }
 
+/// Tell the player why the level can't be won any more, and how to move on
+pub fn report_failure(
+    failed: Option<Res<LevelFailed>>,
+    mut stuck_events: EventReader<NoMovesLeft>,
+) {
+    let stuck = stuck_events.iter().count() > 0;
+
+    let reason = match failed {
+        Some(failed) if failed.is_added() => failed.0,
+        _ if stuck => FailReason::NoMovesLeft,
+        _ => return,
+    };
+
+    info!(
+        "{}, press R to restart the level or Space to skip it",
+        reason
+    );
+}
+
 pub fn unload_level(mut commands: Commands, mut level_query: Query<Entity, With<LevelComponent>>) {
     commands.remove_resource::<WinTimer>();
+    commands.remove_resource::<LevelFailed>();
+    commands.remove_resource::<FallTimer>();
 
     for item in level_query.iter_mut() {
-        commands.entity(item).despawn();
+        commands.entity(item).despawn_recursive();
     }
 }
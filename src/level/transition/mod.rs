@@ -0,0 +1,6 @@
+mod components;
+mod plugin;
+mod resources;
+mod systems;
+
+pub use plugin::LevelTransitionPlugin;
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::State;
+
+use super::{
+    resources::TransitionTimer,
+    systems::{fade_in, fade_out, spawn_overlay, start_transition},
+};
+
+pub struct LevelTransitionPlugin;
+
+impl Plugin for LevelTransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_exit_system(State::AssetLoading, spawn_overlay)
+            .add_enter_system(State::LevelIntro, start_transition)
+            .add_enter_system(State::LevelOutro, start_transition)
+            .add_system(
+                fade_in
+                    .run_if_resource_exists::<TransitionTimer>()
+                    .run_in_state(State::LevelIntro),
+            )
+            .add_system(
+                fade_out
+                    .run_if_resource_exists::<TransitionTimer>()
+                    .run_in_state(State::LevelOutro),
+            );
+    }
+}
@@ -0,0 +1,4 @@
+use bevy::{prelude::Resource, time::Timer};
+
+#[derive(Clone, Debug, Resource)]
+pub struct TransitionTimer(pub Timer);
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::{grid::RenderLayer, State};
+
+use super::{components::TransitionOverlay, resources::TransitionTimer};
+
+const TRANSITION_SECONDS: f32 = 0.4;
+
+pub fn spawn_overlay(mut commands: Commands) {
+    // Start opaque, so the first level fades in like every other one
+    commands
+        .spawn(SpriteBundle {
+            sprite: Sprite {
+                color: Color::BLACK,
+                custom_size: Some(Vec2::splat(100_000.0)),
+                ..Default::default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, RenderLayer::Overlay.z()),
+            ..Default::default()
+        })
+        .insert(TransitionOverlay);
+}
+
+pub fn start_transition(mut commands: Commands) {
+    commands.insert_resource(TransitionTimer(Timer::from_seconds(
+        TRANSITION_SECONDS,
+        TimerMode::Once,
+    )));
+}
+
+pub fn fade_in(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: ResMut<TransitionTimer>,
+    mut overlay_query: Query<&mut Sprite, With<TransitionOverlay>>,
+) {
+    timer.0.tick(time.delta());
+
+    for mut sprite in overlay_query.iter_mut() {
+        sprite.color.set_a(timer.0.percent_left());
+    }
+
+    if timer.0.just_finished() {
+        commands.remove_resource::<TransitionTimer>();
+        commands.insert_resource(NextState(State::InLevel));
+    }
+}
+
+pub fn fade_out(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: ResMut<TransitionTimer>,
+    mut overlay_query: Query<&mut Sprite, With<TransitionOverlay>>,
+) {
+    timer.0.tick(time.delta());
+
+    for mut sprite in overlay_query.iter_mut() {
+        sprite.color.set_a(timer.0.percent());
+    }
+
+    if timer.0.just_finished() {
+        commands.remove_resource::<TransitionTimer>();
+        commands.insert_resource(NextState(State::LevelLoading));
+    }
+}
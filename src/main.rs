@@ -1,18 +1,18 @@
-use bevy::{
-    core_pipeline::clear_color::ClearColorConfig, prelude::*, window::WindowDescriptor,
-    DefaultPlugins,
-};
+use bevy::{prelude::*, window::WindowDescriptor, DefaultPlugins};
 use bevy_asset_loader::prelude::*;
 use iyes_loopless::prelude::*;
 use leafwing_input_manager::prelude::*;
 
 use crate::{
     action::Action,
-    stage::{EntityFinalisationStage, EntityProcessingStage, InputHandlingStage},
+    stage::{
+        EntityFinalisationStage, EntityProcessingStage, InputHandlingStage, MovementResolutionStage,
+    },
     util::prelude::*,
 };
 
 mod action;
+mod camera;
 mod direction;
 mod entities;
 mod grid;
@@ -25,21 +25,9 @@ mod util;
 enum State {
     AssetLoading,
     LevelLoading,
+    LevelIntro,
     InLevel,
-}
-
-fn spawn_camera(mut commands: Commands) {
-    commands.spawn(Camera2dBundle {
-        camera_2d: Camera2d {
-            clear_color: ClearColorConfig::Custom(Color::Rgba {
-                red: 0.15,
-                green: 0.55,
-                blue: 0.35,
-                alpha: 1.0,
-            }),
-        },
-        ..Default::default()
-    });
+    LevelOutro,
 }
 
 fn main() {
@@ -85,6 +73,11 @@ fn main() {
         )
         .add_stage_before(
             EntityProcessingStage,
+            MovementResolutionStage,
+            SystemStage::parallel(),
+        )
+        .add_stage_before(
+            MovementResolutionStage,
             InputHandlingStage,
             SystemStage::parallel(),
         )
@@ -94,9 +87,7 @@ fn main() {
         .add_plugin(grid::GridPlugin)
         .add_plugin(movement::MovementPlugin)
         .add_plugins(entities::EntityPlugins)
-
-        // Setup the camera
-        .add_exit_system(State::AssetLoading, spawn_camera)
+        .add_plugin(camera::CameraPlugin)
 
         // Run the game 🚀
         .run();
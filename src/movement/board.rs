@@ -0,0 +1,324 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    direction::Direction,
+    grid::{GridPosition, GridShape, GridSize, GridWrap},
+};
+
+use super::{
+    components::{Blocker, Door, Inventory, Key, KeyColour, OneWay, Portal, Slippery},
+    topology::Topology,
+};
+
+#[derive(SystemParam)]
+pub struct BoardQuery<'w, 's> {
+    grid_query: Query<'w, 's, (&'static GridSize, &'static GridWrap, &'static GridShape)>,
+    blockers_query: Query<'w, 's, &'static GridPosition, With<Blocker>>,
+    slippery_query: Query<'w, 's, &'static GridPosition, With<Slippery>>,
+    portals_query: Query<'w, 's, (&'static GridPosition, &'static Portal)>,
+    one_way_query: Query<'w, 's, (&'static GridPosition, &'static OneWay)>,
+    keys_query: Query<'w, 's, (Entity, &'static GridPosition, &'static Key)>,
+    doors_query: Query<'w, 's, (Entity, &'static GridPosition, &'static Door)>,
+}
+
+/// Everything on the grid that decides where a head can move, as it stood before this move
+pub struct Board {
+    pub shape: GridShape,
+    size: Option<GridSize>,
+    blockers: HashSet<GridPosition>,
+    slippery: HashSet<GridPosition>,
+    topology: Topology,
+    one_way: HashMap<GridPosition, Direction>,
+    keys: HashMap<GridPosition, (Entity, KeyColour)>,
+    doors: HashMap<GridPosition, (Entity, KeyColour)>,
+
+    /// Keys picked up and doors opened by the moves resolved so far
+    pub used: Vec<Entity>,
+}
+
+impl<'w, 's> BoardQuery<'w, 's> {
+    pub fn board(&self) -> Board {
+        let (grid, shape) = match self.grid_query.get_single() {
+            Ok((size, wrap, shape)) => (Some((*size, *wrap)), *shape),
+            Err(_) => (None, GridShape::default()),
+        };
+
+        Board {
+            shape,
+            size: grid.map(|(size, _)| size),
+            blockers: self.blockers_query.iter().copied().collect(),
+            slippery: self.slippery_query.iter().copied().collect(),
+            topology: Topology::new(grid, self.portals_query.iter()),
+            one_way: self
+                .one_way_query
+                .iter()
+                .map(|(position, one_way)| (*position, one_way.direction))
+                .collect(),
+            keys: self
+                .keys_query
+                .iter()
+                .map(|(entity, position, key)| (*position, (entity, key.colour)))
+                .collect(),
+            doors: self
+                .doors_query
+                .iter()
+                .map(|(entity, position, door)| (*position, (entity, door.colour)))
+                .collect(),
+            used: Vec::new(),
+        }
+    }
+}
+
+impl Board {
+    pub fn in_bounds(&self, position: GridPosition) -> bool {
+        match self.size {
+            Some(max) => {
+                position.x >= 0
+                    && position.x < max.width as i32
+                    && position.y >= 0
+                    && position.y < max.height as i32
+            }
+            None => true,
+        }
+    }
+
+    /// The cell in front of `position`
+    pub fn facing(&self, position: GridPosition, direction: Direction) -> GridPosition {
+        self.topology.facing(position, direction)
+    }
+
+    /// Step onto `position` if possible, opening doors and picking up keys on the way
+    fn enter(
+        &self,
+        position: GridPosition,
+        direction: Direction,
+        inventory: &mut Inventory,
+        used: &mut Vec<Entity>,
+    ) -> bool {
+        if !self.in_bounds(position) || self.blockers.contains(&position) {
+            return false;
+        }
+
+        if let Some(one_way) = self.one_way.get(&position) {
+            if *one_way != direction {
+                return false;
+            }
+        }
+
+        if let Some((entity, colour)) = self.doors.get(&position) {
+            if !self.used.contains(entity) && !used.contains(entity) {
+                match inventory.0.iter().position(|key| key == colour) {
+                    Some(index) => {
+                        inventory.0.remove(index);
+                        used.push(*entity);
+                    }
+                    None => return false,
+                }
+            }
+        }
+
+        if let Some((entity, colour)) = self.keys.get(&position) {
+            if !self.used.contains(entity) && !used.contains(entity) {
+                inventory.0.push(*colour);
+                used.push(*entity);
+            }
+        }
+
+        true
+    }
+
+    /// Resolve a head at `position` moving in `direction`, returning every cell it crosses. The
+    /// inventory and the board are only updated if the move succeeds.
+    pub fn resolve(
+        &mut self,
+        position: GridPosition,
+        direction: Direction,
+        inventory: &mut Inventory,
+    ) -> Option<Vec<GridPosition>> {
+        let mut path = vec![self.step(position, direction, inventory)?];
+
+        while self.slippery.contains(path.last().unwrap()) {
+            let last_position = *path.last().unwrap();
+
+            // Portals can send a slide back over its own path, so stop rather than loop forever
+            if path.contains(&self.topology.facing(last_position, direction)) {
+                break;
+            }
+
+            match self.step(last_position, direction, inventory) {
+                Some(next_position) => path.push(next_position),
+                None => break,
+            }
+        }
+
+        Some(path)
+    }
+
+    /// Move a head a single cell without sliding, as when falling, returning where it ends up.
+    /// The inventory and the board are only updated if the step succeeds.
+    pub fn step(
+        &mut self,
+        position: GridPosition,
+        direction: Direction,
+        inventory: &mut Inventory,
+    ) -> Option<GridPosition> {
+        let mut proposed_inventory = inventory.clone();
+        let mut used = Vec::new();
+
+        let proposed_position = self.topology.facing(position, direction);
+
+        if !self.enter(
+            proposed_position,
+            direction,
+            &mut proposed_inventory,
+            &mut used,
+        ) {
+            return None;
+        }
+
+        *inventory = proposed_inventory;
+        self.used.extend(used);
+
+        Some(proposed_position)
+    }
+
+    /// Whether something that can't carry keys, like a knight, could stand on `position`
+    pub fn is_open(&self, position: GridPosition) -> bool {
+        self.in_bounds(position)
+            && !self.blockers.contains(&position)
+            && !self.doors.contains_key(&position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(width: u32, height: u32, wrap: GridWrap) -> Board {
+        let size = GridSize::new(width, height);
+
+        Board {
+            shape: GridShape::Square,
+            size: Some(size),
+            blockers: HashSet::new(),
+            slippery: HashSet::new(),
+            topology: Topology::new(Some((size, wrap)), std::iter::empty()),
+            one_way: HashMap::new(),
+            keys: HashMap::new(),
+            doors: HashMap::new(),
+            used: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn door_without_key_blocks() {
+        let mut board = board(3, 1, GridWrap::None);
+        board.doors.insert(
+            GridPosition::new(1, 0),
+            (Entity::from_raw(0), KeyColour::Red),
+        );
+
+        let mut inventory = Inventory::default();
+
+        assert_eq!(
+            board.resolve(GridPosition::new(0, 0), Direction::Right, &mut inventory),
+            None
+        );
+        assert!(board.used.is_empty());
+    }
+
+    #[test]
+    fn door_with_key_opens_and_uses_the_key() {
+        let door = Entity::from_raw(0);
+        let mut board = board(3, 1, GridWrap::None);
+        board
+            .doors
+            .insert(GridPosition::new(1, 0), (door, KeyColour::Red));
+
+        let mut inventory = Inventory(vec![KeyColour::Red]);
+
+        assert_eq!(
+            board.resolve(GridPosition::new(0, 0), Direction::Right, &mut inventory),
+            Some(vec![GridPosition::new(1, 0)])
+        );
+        assert!(inventory.0.is_empty());
+        assert_eq!(board.used, vec![door]);
+    }
+
+    #[test]
+    fn ice_slide_stops_before_a_blocker() {
+        let mut board = board(5, 1, GridWrap::None);
+        board
+            .slippery
+            .extend((1..4).map(|x| GridPosition::new(x, 0)));
+        board.blockers.insert(GridPosition::new(4, 0));
+
+        assert_eq!(
+            board.resolve(
+                GridPosition::new(0, 0),
+                Direction::Right,
+                &mut Inventory::default()
+            ),
+            Some((1..4).map(|x| GridPosition::new(x, 0)).collect::<Vec<_>>())
+        );
+    }
+
+    #[test]
+    fn portal_exits_beside_its_partner() {
+        let size = GridSize::new(3, 3);
+        let portals = [
+            (
+                GridPosition::new(1, 0),
+                Portal {
+                    partner: GridPosition::new(1, 2),
+                },
+            ),
+            (
+                GridPosition::new(1, 2),
+                Portal {
+                    partner: GridPosition::new(1, 0),
+                },
+            ),
+        ];
+
+        let mut board = board(3, 3, GridWrap::None);
+        board.topology = Topology::new(
+            Some((size, GridWrap::None)),
+            portals.iter().map(|(position, portal)| (position, portal)),
+        );
+
+        assert_eq!(
+            board.resolve(
+                GridPosition::new(0, 0),
+                Direction::Right,
+                &mut Inventory::default()
+            ),
+            Some(vec![GridPosition::new(2, 2)])
+        );
+    }
+
+    #[test]
+    fn wrapping_edges_lead_to_the_other_side() {
+        let mut wrapped = board(3, 1, GridWrap::Horizontal);
+        let mut bounded = board(3, 1, GridWrap::None);
+
+        assert_eq!(
+            wrapped.resolve(
+                GridPosition::new(2, 0),
+                Direction::Right,
+                &mut Inventory::default()
+            ),
+            Some(vec![GridPosition::new(0, 0)])
+        );
+        assert_eq!(
+            bounded.resolve(
+                GridPosition::new(2, 0),
+                Direction::Right,
+                &mut Inventory::default()
+            ),
+            None
+        );
+    }
+}
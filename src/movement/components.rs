@@ -1,12 +1,169 @@
 use bevy::prelude::Component;
+use serde::Deserialize;
 
-use crate::grid::GridPosition;
+use crate::{
+    action::MovementAction,
+    direction::Direction,
+    grid::{GridPosition, GridShape},
+};
 
 #[derive(Component)]
 pub struct MovementManager;
 
+/// What the turning actions do in a level
+#[derive(Deserialize, Component, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum TurnMode {
+    /// Turn and step forwards in the new direction
+    #[default]
+    TurnAndMove,
+    /// Turn without leaving the cell
+    TurnOnly,
+    /// Step sideways while still facing the same way
+    Strafe,
+}
+
+/// Whether the level lets dragons back up
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AllowReverse(pub bool);
+
+/// The direction unsupported dragons fall in, if the level has gravity
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Gravity(pub Option<Direction>);
+
+impl TurnMode {
+    /// The direction a head ends up facing after an action, and the direction it steps in, if
+    /// it moves at all
+    pub fn apply(
+        self,
+        direction: Direction,
+        action: MovementAction,
+        shape: GridShape,
+    ) -> (Direction, Option<Direction>) {
+        let turned = direction.process_action(action, shape);
+
+        match (self, action) {
+            (_, MovementAction::Backwards) => (direction, Some(direction.opposite())),
+            (TurnMode::TurnAndMove, _) | (_, MovementAction::Forwards) => (turned, Some(turned)),
+            (TurnMode::TurnOnly, _) => (turned, None),
+            (TurnMode::Strafe, _) => (direction, Some(turned)),
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct Blocker;
 
+/// Heads moving onto a slippery cell keep travelling until something stops them
+#[derive(Component)]
+pub struct Slippery;
+
+/// Heads stepping onto a portal come out of its partner, keeping their direction
+#[derive(Component, Clone, Copy)]
+pub struct Portal {
+    pub partner: GridPosition,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum KeyColour {
+    Red,
+    Green,
+    Blue,
+    Yellow,
+}
+
+/// Picked up by the first head to move onto it
+#[derive(Component, Clone, Copy)]
+pub struct Key {
+    pub colour: KeyColour,
+}
+
+/// Only passable by a head holding a key of the same colour, which is used up opening it
+#[derive(Component, Clone, Copy)]
+pub struct Door {
+    pub colour: KeyColour,
+}
+
+/// Can only be entered moving in its direction
+#[derive(Component, Clone, Copy)]
+pub struct OneWay {
+    pub direction: Direction,
+}
+
+/// Pushes a head landing on it one more cell in its direction
+#[derive(Component, Clone, Copy)]
+pub struct Conveyor {
+    pub direction: Direction,
+}
+
+/// Changed by a dragon breathing fire onto its cell
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Flammable {
+    /// Burnt or melted away
+    Consumed,
+    /// Set alight, staying lit for the rest of the level
+    Ignited,
+}
+
+/// Set alight by a dragon's fire
+#[derive(Component)]
+pub struct Lit;
+
+/// Keys held by a dragon
+#[derive(Component, Clone, Default, Debug)]
+pub struct Inventory(pub Vec<KeyColour>);
+
+/// A pending move, emptied once it has been applied
 #[derive(Component, Default)]
-pub struct Movement(pub Option<GridPosition>);
+pub struct Movement {
+    /// The cells crossed, ending at the destination
+    pub path: Vec<GridPosition>,
+    /// Which way the head stepped, which differs from the way it faces when strafing
+    pub direction: Option<Direction>,
+    /// Made by the backwards action, which winds the body back in along its trail
+    pub backwards: bool,
+    /// A single step down under gravity, which conveyors leave alone
+    pub falling: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn_and_move_steps_the_new_way() {
+        assert_eq!(
+            TurnMode::TurnAndMove.apply(Direction::Up, MovementAction::TurnLeft, GridShape::Square),
+            (Direction::Left, Some(Direction::Left))
+        );
+    }
+
+    #[test]
+    fn turn_only_stays_in_place() {
+        assert_eq!(
+            TurnMode::TurnOnly.apply(Direction::Up, MovementAction::TurnRight, GridShape::Square),
+            (Direction::Right, None)
+        );
+        assert_eq!(
+            TurnMode::TurnOnly.apply(Direction::Up, MovementAction::Forwards, GridShape::Square),
+            (Direction::Up, Some(Direction::Up))
+        );
+    }
+
+    #[test]
+    fn strafe_keeps_facing_forwards() {
+        assert_eq!(
+            TurnMode::Strafe.apply(Direction::Up, MovementAction::TurnLeft, GridShape::Square),
+            (Direction::Up, Some(Direction::Left))
+        );
+    }
+
+    #[test]
+    fn backwards_steps_away_without_turning() {
+        for turn_mode in [TurnMode::TurnAndMove, TurnMode::TurnOnly, TurnMode::Strafe] {
+            assert_eq!(
+                turn_mode.apply(Direction::Up, MovementAction::Backwards, GridShape::Square),
+                (Direction::Up, Some(Direction::Down))
+            );
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::grid::GridPosition;
+
+/// Sent when the player takes a turn, by moving or turning at least one dragon or breathing fire
+#[derive(Clone, Copy, Debug)]
+pub struct Moved;
+
+/// Sent after a move when none of the movement actions can move any dragon, and no dragon is
+/// facing a blocker it could burn away
+#[derive(Clone, Copy, Debug)]
+pub struct NoMovesLeft;
+
+/// Sent for every cell a dragon breathes fire onto
+#[derive(Clone, Copy, Debug)]
+pub struct FireBreathed {
+    pub position: GridPosition,
+}
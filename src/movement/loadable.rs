@@ -7,11 +7,14 @@ use crate::{
     util::prelude::*,
 };
 
-use super::components::MovementManager;
+use super::components::{AllowReverse, Gravity, MovementManager, TurnMode};
 
 #[derive(Bundle)]
 pub struct MovementBundle {
     manager: MovementManager,
+    turn_mode: TurnMode,
+    allow_reverse: AllowReverse,
+    gravity: Gravity,
     component: LevelComponent,
 
     #[bundle]
@@ -19,9 +22,12 @@ pub struct MovementBundle {
 }
 
 impl MovementBundle {
-    pub fn new() -> Self {
+    pub fn new(turn_mode: TurnMode, allow_reverse: AllowReverse, gravity: Gravity) -> Self {
         Self {
             manager: MovementManager,
+            turn_mode,
+            allow_reverse,
+            gravity,
             component: LevelComponent,
 
             input_manager: InputManagerBundle::<Action> {
@@ -30,8 +36,11 @@ impl MovementBundle {
                     (KeyCode::Up, Action::MovementForwards),
                     (KeyCode::A, Action::MovementTurnLeft),
                     (KeyCode::D, Action::MovementTurnRight),
+                    (KeyCode::S, Action::MovementBackwards),
+                    (KeyCode::Down, Action::MovementBackwards),
                     (KeyCode::Left, Action::MovementTurnLeft),
                     (KeyCode::Right, Action::MovementTurnRight),
+                    (KeyCode::F, Action::BreatheFire),
                 ]),
                 ..Default::default()
             },
@@ -40,7 +49,11 @@ impl MovementBundle {
 }
 
 impl Loadable<LevelConfig> for MovementBundle {
-    fn from_scene(world: &mut World, _: &LevelConfig) {
-        world.spawn_batch([MovementBundle::new()]);
+    fn from_scene(world: &mut World, level: &LevelConfig) {
+        world.spawn_batch([MovementBundle::new(
+            level.turn_mode,
+            AllowReverse(level.allow_reverse),
+            Gravity(level.gravity),
+        )]);
     }
 }
@@ -1,9 +1,18 @@
+mod board;
 mod components;
+mod events;
 mod loadable;
 mod plugin;
 mod systems;
+mod topology;
 
 pub use self::{
-    components::{Blocker, Movement},
+    board::BoardQuery,
+    components::{
+        Blocker, Conveyor, Door, Flammable, Inventory, Key, KeyColour, Lit, Movement, OneWay,
+        Portal, Slippery, TurnMode,
+    },
+    events::{FireBreathed, Moved, NoMovesLeft},
     plugin::MovementPlugin,
+    topology::Topology,
 };
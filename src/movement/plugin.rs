@@ -2,14 +2,19 @@ use bevy::prelude::*;
 use iyes_loopless::prelude::*;
 
 use crate::{
-    stage::{EntityFinalisationStage, InputHandlingStage},
+    level::{FallTimer, LevelFailed, WinTimer},
+    stage::{EntityFinalisationStage, InputHandlingStage, MovementResolutionStage, PathExtension},
     util::prelude::*,
     State,
 };
 
 use super::{
+    events::{FireBreathed, Moved, NoMovesLeft},
     loadable::MovementBundle,
-    systems::{finish_movement, process_movement},
+    systems::{
+        apply_conveyors, apply_gravity, breathe_fire, check_stuck, check_support, finish_movement,
+        process_movement,
+    },
 };
 
 pub struct MovementPlugin;
@@ -17,19 +22,61 @@ pub struct MovementPlugin;
 impl Plugin for MovementPlugin {
     fn build(&self, app: &mut App) {
         app.register_loadable::<MovementBundle>()
+            .add_event::<NoMovesLeft>()
+            .add_event::<FireBreathed>()
+            .add_event::<Moved>()
             .add_system_set_to_stage(
                 InputHandlingStage,
                 ConditionSet::new()
                     .run_in_state(State::InLevel)
+                    .run_unless_resource_exists::<LevelFailed>()
+                    .run_unless_resource_exists::<FallTimer>()
                     .with_system(process_movement)
+                    .with_system(breathe_fire)
                     .into(),
             )
+            .add_system_set_to_stage(
+                InputHandlingStage,
+                ConditionSet::new()
+                    .run_in_state(State::InLevel)
+                    .run_unless_resource_exists::<LevelFailed>()
+                    .run_if_resource_exists::<FallTimer>()
+                    .with_system(apply_gravity)
+                    .into(),
+            )
+            .add_system_to_stage(
+                MovementResolutionStage,
+                apply_conveyors
+                    .run_in_state(State::InLevel)
+                    .label(PathExtension),
+            )
             .add_system_set_to_stage(
                 EntityFinalisationStage,
                 ConditionSet::new()
                     .run_in_state(State::InLevel)
                     .with_system(finish_movement)
                     .into(),
+            )
+            // Falls start as soon as a move lands, before anything checks for a win or being stuck
+            .add_system_to_stage(
+                EntityFinalisationStage,
+                check_support
+                    .run_in_state(State::InLevel)
+                    .run_unless_resource_exists::<LevelFailed>()
+                    .run_unless_resource_exists::<WinTimer>()
+                    .run_unless_resource_exists::<FallTimer>()
+                    .after(finish_movement),
+            )
+            // Gates only open or close once post-update commands apply, so wait until after them
+            .add_system_set_to_stage(
+                CoreStage::Last,
+                ConditionSet::new()
+                    .run_in_state(State::InLevel)
+                    .run_unless_resource_exists::<LevelFailed>()
+                    .run_unless_resource_exists::<WinTimer>()
+                    .run_unless_resource_exists::<FallTimer>()
+                    .with_system(check_stuck)
+                    .into(),
             );
     }
 }
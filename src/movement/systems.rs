@@ -1,59 +1,319 @@
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 
 use crate::{
-    action::Action,
+    action::{Action, MovementAction},
     direction::Direction,
-    grid::{GridPosition, GridSize},
+    entities::dragon::BodySegment,
+    grid::GridPosition,
+    level::FallTimer,
+};
+
+use super::{
+    board::BoardQuery,
+    components::{
+        AllowReverse, Blocker, Conveyor, Flammable, Gravity, Inventory, Lit, Movement,
+        MovementManager, TurnMode,
+    },
+    events::{FireBreathed, Moved, NoMovesLeft},
 };
 
-use super::components::{Blocker, Movement, MovementManager};
+/// Seconds between each cell a falling dragon drops
+const FALL_STEP: f32 = 0.08;
 
 pub fn process_movement(
-    actions_query: Query<&ActionState<Action>, With<MovementManager>>,
-    grid_query: Query<&GridSize>,
-    blockers_query: Query<&GridPosition, With<Blocker>>,
-    mut movement_query: Query<(&GridPosition, &mut Direction, &mut Movement)>,
+    mut commands: Commands,
+    actions_query: Query<(&ActionState<Action>, &TurnMode, &AllowReverse), With<MovementManager>>,
+    board_query: BoardQuery,
+    mut movement_query: Query<(&GridPosition, &mut Direction, &mut Movement, &mut Inventory)>,
+    mut moved_events: EventWriter<Moved>,
 ) {
-    for action in actions_query.single().get_just_pressed() {
+    let (action_state, turn_mode, allow_reverse) = actions_query.single();
+
+    for action in action_state.get_just_pressed() {
         let action = match action.movement() {
+            Some(MovementAction::Backwards) if !allow_reverse.0 => continue,
             Some(action) => action,
             _ => continue,
         };
 
-        let movement_max = grid_query.get_single().ok();
+        let mut board = board_query.board();
+        let mut moved = false;
 
-        for (position, mut direction, mut movement) in movement_query.iter_mut() {
-            let proposed_direction = direction.process_action(action);
-            let proposed_position = position.apply_direction(proposed_direction);
+        for (position, mut direction, mut movement, mut inventory) in movement_query.iter_mut() {
+            let (proposed_direction, step) = turn_mode.apply(*direction, action, board.shape);
 
-            if let Some(max) = movement_max {
-                if proposed_position.x < 0
-                    || proposed_position.x >= max.width as i32
-                    || proposed_position.y < 0
-                    || proposed_position.y >= max.height as i32
-                {
-                    continue;
+            match step {
+                Some(step) => {
+                    if let Some(path) = board.resolve(*position, step, &mut inventory) {
+                        *direction = proposed_direction;
+                        *movement = Movement {
+                            path,
+                            direction: Some(step),
+                            backwards: matches!(action, MovementAction::Backwards),
+                            falling: false,
+                        };
+                        moved = true;
+                    }
+                }
+                None => {
+                    *direction = proposed_direction;
+                    moved = true;
                 }
             }
+        }
 
-            if blockers_query
-                .iter()
-                .any(|blocker| *blocker == proposed_position)
-            {
-                continue;
+        if moved {
+            moved_events.send(Moved);
+        }
+
+        for entity in board.used {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn breathe_fire(
+    mut commands: Commands,
+    actions_query: Query<&ActionState<Action>, With<MovementManager>>,
+    board_query: BoardQuery,
+    heads_query: Query<(&GridPosition, &Direction), With<Movement>>,
+    flammable_query: Query<(Entity, &GridPosition, &Flammable)>,
+    mut fire_events: EventWriter<FireBreathed>,
+    mut moved_events: EventWriter<Moved>,
+) {
+    if !actions_query.single().just_pressed(Action::BreatheFire) {
+        return;
+    }
+
+    let board = board_query.board();
+
+    let targets = heads_query
+        .iter()
+        .map(|(position, direction)| board.facing(*position, *direction))
+        .filter(|target| board.in_bounds(*target))
+        .collect::<HashSet<_>>();
+
+    for (entity, position, flammable) in flammable_query.iter() {
+        if !targets.contains(position) {
+            continue;
+        }
+
+        match flammable {
+            Flammable::Consumed => commands.entity(entity).despawn_recursive(),
+            Flammable::Ignited => {
+                commands.entity(entity).remove::<Flammable>().insert(Lit);
             }
+        }
+    }
+
+    fire_events.send_batch(
+        targets
+            .into_iter()
+            .map(|position| FireBreathed { position }),
+    );
+
+    // Breathing fire takes a turn just like moving does
+    moved_events.send(Moved);
+}
+
+pub fn apply_conveyors(
+    mut commands: Commands,
+    board_query: BoardQuery,
+    conveyors_query: Query<(&GridPosition, &Conveyor)>,
+    mut movement_query: Query<(&mut Movement, &mut Inventory), Changed<Movement>>,
+) {
+    let mut board = board_query.board();
+
+    for (mut movement, mut inventory) in movement_query.iter_mut() {
+        if movement.falling {
+            continue;
+        }
+
+        let destination = match movement.path.last() {
+            Some(destination) => *destination,
+            None => continue,
+        };
+
+        let conveyor = match conveyors_query
+            .iter()
+            .find(|(position, _)| **position == destination)
+        {
+            Some((_, conveyor)) => conveyor,
+            None => continue,
+        };
+
+        if let Some(path) = board.resolve(destination, conveyor.direction, &mut inventory) {
+            movement.path.extend(path);
+        }
+    }
+
+    for entity in board.used {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Start dragons falling whenever one is left unsupported, whether by a move, something burning
+/// or opening beneath it, hatching in mid-air or the level starting that way
+pub fn check_support(
+    mut commands: Commands,
+    gravity_query: Query<&Gravity, With<MovementManager>>,
+    board_query: BoardQuery,
+    segments_query: Query<&GridPosition, With<BodySegment>>,
+    heads_query: Query<(&GridPosition, &Inventory), With<Movement>>,
+) {
+    let gravity = match gravity_query.get_single() {
+        Ok(Gravity(Some(gravity))) => *gravity,
+        _ => return,
+    };
+
+    let mut board = board_query.board();
+    let supports = resting_cells(
+        &segments_query,
+        heads_query.iter().map(|(position, _)| position),
+    );
+
+    let unsupported = heads_query.iter().any(|(position, inventory)| {
+        !supports.contains(&board.facing(*position, gravity))
+            && board
+                .step(*position, gravity, &mut inventory.clone())
+                .is_some()
+    });
 
-            *direction = proposed_direction;
-            movement.0 = Some(proposed_position);
+    if unsupported {
+        commands.insert_resource(FallTimer(Timer::from_seconds(FALL_STEP, TimerMode::Once)));
+    }
+}
+
+pub fn apply_gravity(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: ResMut<FallTimer>,
+    gravity_query: Query<&Gravity, With<MovementManager>>,
+    board_query: BoardQuery,
+    segments_query: Query<&GridPosition, With<BodySegment>>,
+    mut heads_query: Query<(&GridPosition, &mut Movement, &mut Inventory)>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let gravity = match gravity_query.get_single() {
+        Ok(Gravity(Some(gravity))) => *gravity,
+        _ => {
+            commands.remove_resource::<FallTimer>();
+            return;
         }
+    };
+
+    let mut board = board_query.board();
+    let supports = resting_cells(
+        &segments_query,
+        heads_query.iter().map(|(position, _, _)| position),
+    );
+
+    let mut falling = false;
+
+    for (position, mut movement, mut inventory) in heads_query.iter_mut() {
+        if supports.contains(&board.facing(*position, gravity)) {
+            continue;
+        }
+
+        if let Some(below) = board.step(*position, gravity, &mut inventory) {
+            *movement = Movement {
+                path: vec![below],
+                direction: Some(gravity),
+                backwards: false,
+                falling: true,
+            };
+            falling = true;
+        }
+    }
+
+    for entity in board.used {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if falling {
+        timer.0.reset();
+    } else {
+        commands.remove_resource::<FallTimer>();
+    }
+}
+
+/// Report when no action could free any dragon, after every turn, including turns that leave
+/// heads in place, and after every fall or push
+pub fn check_stuck(
+    board_query: BoardQuery,
+    rules_query: Query<(&TurnMode, &AllowReverse), With<MovementManager>>,
+    mut moved_events: EventReader<Moved>,
+    moved_query: Query<Entity, (With<Movement>, Changed<GridPosition>)>,
+    dragons_query: Query<(&GridPosition, &Direction, &Inventory), With<Movement>>,
+    flammable_query: Query<&GridPosition, (With<Flammable>, With<Blocker>)>,
+    mut stuck_events: EventWriter<NoMovesLeft>,
+) {
+    let turn_taken = moved_events.iter().count() > 0;
+
+    if (!turn_taken && moved_query.is_empty()) || dragons_query.is_empty() {
+        return;
+    }
+
+    let mut board = board_query.board();
+    let (turn_mode, allow_reverse) = match rules_query.get_single() {
+        Ok((turn_mode, allow_reverse)) => (*turn_mode, allow_reverse.0),
+        Err(_) => (TurnMode::default(), false),
+    };
+
+    let can_move = MovementAction::ALL
+        .into_iter()
+        .filter(|action| allow_reverse || !matches!(action, MovementAction::Backwards))
+        .any(|action| {
+            dragons_query
+                .iter()
+                .any(|(position, direction, inventory)| {
+                    match turn_mode.apply(*direction, action, board.shape) {
+                        (_, Some(step)) => board
+                            .resolve(*position, step, &mut inventory.clone())
+                            .is_some(),
+                        // Turning on the spot only helps if the head can then step forwards, and
+                        // turns can be repeated to face any direction on the grid
+                        (_, None) => board.shape.directions().iter().any(|turned| {
+                            board
+                                .resolve(*position, *turned, &mut inventory.clone())
+                                .is_some()
+                        }),
+                    }
+                })
+        });
+
+    // Burning only frees a dragon if it clears something in the way, so ice doesn't count
+    let can_burn = dragons_query.iter().any(|(position, direction, _)| {
+        let target = board.facing(*position, *direction);
+        flammable_query.iter().any(|flammable| *flammable == target)
+    });
+
+    if !can_move && !can_burn {
+        stuck_events.send(NoMovesLeft);
     }
 }
 
 pub fn finish_movement(mut query: Query<(&mut GridPosition, &mut Movement), Changed<Movement>>) {
     for (mut position, mut movement) in query.iter_mut() {
-        if let Some(proposed_position) = movement.0.take() {
+        if let Some(proposed_position) = movement.path.drain(..).last() {
             *position = proposed_position;
         }
     }
 }
+
+/// Cells a head can rest on besides blockers and the edge of the grid, which are dragon bodies
+/// and other heads
+fn resting_cells<'a>(
+    segments_query: &Query<&GridPosition, With<BodySegment>>,
+    heads: impl Iterator<Item = &'a GridPosition>,
+) -> HashSet<GridPosition> {
+    let mut cells = segments_query.iter().copied().collect::<HashSet<_>>();
+    cells.extend(heads.copied());
+    cells
+}
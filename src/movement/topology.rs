@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::{
+    direction::Direction,
+    grid::{GridPosition, GridSize, GridWrap},
+};
+
+use super::components::Portal;
+
+/// How cells connect to each other, through wrapping edges and portals
+pub struct Topology {
+    grid: Option<(GridSize, GridWrap)>,
+    portals: HashMap<GridPosition, GridPosition>,
+}
+
+impl Topology {
+    pub fn new<'a>(
+        grid: Option<(GridSize, GridWrap)>,
+        portals: impl Iterator<Item = (&'a GridPosition, &'a Portal)>,
+    ) -> Self {
+        Self {
+            grid,
+            portals: portals
+                .map(|(position, portal)| (*position, portal.partner))
+                .collect(),
+        }
+    }
+
+    fn step(&self, position: GridPosition, direction: Direction) -> GridPosition {
+        match self.grid {
+            Some((size, wrap)) => position.apply_direction_wrapped(direction, size, wrap),
+            None => position.apply_direction(direction),
+        }
+    }
+
+    /// The cell in front of `position`, stepping through a portal if there is one in the way
+    pub fn facing(&self, position: GridPosition, direction: Direction) -> GridPosition {
+        let next_position = self.step(position, direction);
+
+        match self.portals.get(&next_position) {
+            Some(partner) => self.step(*partner, direction),
+            None => next_position,
+        }
+    }
+}
@@ -1,6 +1,7 @@
-use bevy::prelude::StageLabel;
+use bevy::prelude::{StageLabel, SystemLabel};
 
 pub struct InputHandlingStage;
+pub struct MovementResolutionStage;
 pub struct EntityProcessingStage;
 pub struct EntityFinalisationStage;
 
@@ -10,6 +11,12 @@ impl StageLabel for InputHandlingStage {
     }
 }
 
+impl StageLabel for MovementResolutionStage {
+    fn as_str(&self) -> &'static str {
+        "MovementResolution"
+    }
+}
+
 impl StageLabel for EntityProcessingStage {
     fn as_str(&self) -> &'static str {
         "EntityProcessing"
@@ -21,3 +28,12 @@ impl StageLabel for EntityFinalisationStage {
         "EntityFinalisation"
     }
 }
+
+/// Systems that extend movement paths, for anything that needs to see the whole path
+pub struct PathExtension;
+
+impl SystemLabel for PathExtension {
+    fn as_str(&self) -> &'static str {
+        "PathExtension"
+    }
+}